@@ -0,0 +1,49 @@
+use std::hint::black_box;
+
+use cframe_rs::{CFrame, Float, Vec3};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn points(count: usize) -> Vec<Vec3> {
+    (0..count)
+        .map(|i| {
+            let t = i as Float;
+            Vec3::new(t.sin(), t.cos(), t * 0.001)
+        })
+        .collect()
+}
+
+fn transform(c: &mut Criterion) {
+    let cframe = CFrame::angles(0.3, 1.1, -0.7) + Vec3::new(1.0, 2.0, 3.0);
+    let mut buffer = points(100_000);
+
+    let mut group = c.benchmark_group("transform_points");
+    group.bench_function("per_point", |b| {
+        b.iter(|| {
+            let cframe = black_box(cframe);
+            for point in buffer.iter_mut() {
+                *point = cframe.point_to_world_space(*point);
+            }
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| black_box(cframe).transform_points(black_box(&mut buffer)))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("transform_vectors");
+    group.bench_function("per_vector", |b| {
+        b.iter(|| {
+            let cframe = black_box(cframe);
+            for vector in buffer.iter_mut() {
+                *vector = cframe.vector_to_world_space(*vector);
+            }
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| black_box(cframe).transform_vectors(black_box(&mut buffer)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, transform);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+#[cfg(test)]
+use std::cell::Cell;
+use std::cell::OnceCell;
+
+use crate::CFrame;
+
+/// A `CFrame` paired with a lazily computed inverse.
+///
+/// The inverse is computed on the first call to `inverse()` and reused until
+/// the transform is replaced with `set()`, which clears the cache.
+#[derive(Clone, Debug)]
+pub struct CachedCFrame {
+    transform: CFrame,
+    inverse: OnceCell<CFrame>,
+    /// How many times the inverse has been computed.
+    #[cfg(test)]
+    inversions: Cell<usize>,
+}
+
+impl CachedCFrame {
+    pub fn new(transform: CFrame) -> Self {
+        Self {
+            transform,
+            inverse: OnceCell::new(),
+            #[cfg(test)]
+            inversions: Cell::new(0),
+        }
+    }
+
+    pub fn transform(&self) -> CFrame {
+        self.transform
+    }
+
+    pub fn inverse(&self) -> CFrame {
+        *self.inverse.get_or_init(|| {
+            #[cfg(test)]
+            self.inversions.set(self.inversions.get() + 1);
+            self.transform.inverse()
+        })
+    }
+
+    pub fn set(&mut self, transform: CFrame) {
+        self.transform = transform;
+        self.inverse = OnceCell::new();
+    }
+}
+
+impl From<CFrame> for CachedCFrame {
+    fn from(transform: CFrame) -> Self {
+        Self::new(transform)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec3;
+
+    #[test]
+    fn inverse_is_cached_until_set() {
+        let first = CFrame::from_axis_angle(Vec3::up(), 0.3) + Vec3::new(1.0, 2.0, 3.0);
+        let mut cached = CachedCFrame::new(first);
+        assert_eq!(cached.inversions.get(), 0);
+        assert_eq!(cached.inverse(), first.inverse());
+        assert_eq!(cached.inverse(), first.inverse());
+        assert_eq!(cached.inversions.get(), 1);
+
+        let second = CFrame::from_pos(Vec3::new(-4.0, 5.0, 6.0));
+        cached.set(second);
+        assert_eq!(cached.transform(), second);
+        assert_eq!(cached.inversions.get(), 1);
+        assert_eq!(cached.inverse(), second.inverse());
+        assert_eq!(cached.inverse(), second.inverse());
+        assert_eq!(cached.inversions.get(), 2);
+    }
+}
@@ -3,9 +3,21 @@ use std::{
     ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::{Float, Vec3};
+use crate::{
+    DualQuat, Float, Vec3,
+    consts::{PI, TAU},
+    dual_quat::quat_mul,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
 
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CFrame {
     r11: Float,
     r12: Float,
@@ -22,6 +34,21 @@ pub struct CFrame {
 }
 
 impl CFrame {
+    pub const IDENTITY: CFrame = CFrame {
+        r11: 1.0,
+        r12: 0.0,
+        r13: 0.0,
+        r14: 0.0,
+        r21: 0.0,
+        r22: 1.0,
+        r23: 0.0,
+        r24: 0.0,
+        r31: 0.0,
+        r32: 0.0,
+        r33: 1.0,
+        r34: 0.0,
+    };
+
     pub fn x(&self) -> Vec3 {
         Vec3::new(self.r11, self.r21, self.r31)
     }
@@ -38,24 +65,87 @@ impl CFrame {
         Vec3::new(self.r14, self.r24, self.r34)
     }
 
-    pub fn identity() -> Self {
-        Self {
-            r11: 1.0,
-            r12: 0.0,
-            r13: 0.0,
+    pub fn right(&self) -> Vec3 {
+        self.x()
+    }
+
+    pub fn up_vector(&self) -> Vec3 {
+        self.y()
+    }
+
+    /// The direction the frame faces, `-z()`, since frames look down their
+    /// negative z axis (see `Vec3::forward()` and `perspective`).
+    pub fn look_vector(&self) -> Vec3 {
+        -self.z()
+    }
+
+    /// The same orientation with the position set to zero.
+    pub fn rotation(&self) -> CFrame {
+        CFrame {
             r14: 0.0,
-            r21: 0.0,
-            r22: 1.0,
-            r23: 0.0,
             r24: 0.0,
-            r31: 0.0,
-            r32: 0.0,
-            r33: 1.0,
             r34: 0.0,
+            ..*self
+        }
+    }
+
+    /// The same orientation moved to `pos`.
+    pub fn with_position(&self, pos: Vec3) -> CFrame {
+        CFrame {
+            r14: pos.x,
+            r24: pos.y,
+            r34: pos.z,
+            ..*self
         }
     }
 
-    pub fn from_components(
+    /// Moves the position toward `target` by at most `max_distance`, landing
+    /// exactly on it when it's within range. The rotation is unchanged.
+    pub fn move_toward(&self, target: Vec3, max_distance: Float) -> CFrame {
+        self.with_position(self.p().move_toward(target, max_distance))
+    }
+
+    /// Scales each basis column by the matching component of `scale`,
+    /// leaving the position alone. The result is no longer orthonormal, so
+    /// use `inverse()` rather than `point_to_object_space` to undo it.
+    pub fn scaled(&self, scale: Vec3) -> CFrame {
+        Self::from_columns(
+            self.x() * scale.x,
+            self.y() * scale.y,
+            self.z() * scale.z,
+            self.p(),
+        )
+    }
+
+    /// The length of each basis column, i.e. the scale applied by `scaled`.
+    pub fn extract_scale(&self) -> Vec3 {
+        Vec3::new(
+            self.x().magnitude(),
+            self.y().magnitude(),
+            self.z().magnitude(),
+        )
+    }
+
+    /// Splits the frame into `(position, rotation, scale)`, where the rotation
+    /// is the basis columns divided by the extracted scale. Assumes no shear
+    /// and no zero scale component.
+    pub fn decompose(&self) -> (Vec3, CFrame, Vec3) {
+        let scale = self.extract_scale();
+        let rotation = Self::from_columns(
+            self.x() / scale.x,
+            self.y() / scale.y,
+            self.z() / scale.z,
+            Vec3::zero(),
+        );
+        (self.p(), rotation, scale)
+    }
+
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub const fn from_components(
         m11: Float,
         m12: Float,
         m13: Float,
@@ -85,6 +175,31 @@ impl CFrame {
         }
     }
 
+    /// The twelve components in the order `from_components` takes them:
+    /// `(m11, m12, m13, m14, m21, ..., m34)`, row by row, where the fourth
+    /// entry of each row is the translation.
+    pub fn components(
+        &self,
+    ) -> (
+        Float,
+        Float,
+        Float,
+        Float,
+        Float,
+        Float,
+        Float,
+        Float,
+        Float,
+        Float,
+        Float,
+        Float,
+    ) {
+        (
+            self.r11, self.r12, self.r13, self.r14, self.r21, self.r22, self.r23, self.r24,
+            self.r31, self.r32, self.r33, self.r34,
+        )
+    }
+
     pub fn from_columns(x: Vec3, y: Vec3, z: Vec3, p: Vec3) -> Self {
         Self {
             r11: x.x,
@@ -102,61 +217,140 @@ impl CFrame {
         }
     }
 
+    /// Like `from_columns`, but checks that the columns form an orthonormal
+    /// right-handed basis to within 1e-6, and says which check failed.
+    pub fn try_from_columns(x: Vec3, y: Vec3, z: Vec3, p: Vec3) -> Result<CFrame, &'static str> {
+        const TOLERANCE: Float = 1e-6;
+        if [x, y, z]
+            .iter()
+            .any(|c| (c.magnitude() - 1.0).abs() > TOLERANCE)
+        {
+            return Err("basis column is not unit length");
+        }
+        if [x.dot(y), y.dot(z), z.dot(x)]
+            .iter()
+            .any(|d| d.abs() > TOLERANCE)
+        {
+            return Err("basis columns are not perpendicular");
+        }
+        if x.cross(y).dot(z) < 0.0 {
+            return Err("basis is left-handed");
+        }
+        Ok(Self::from_columns(x, y, z, p))
+    }
+
+    /// Builds a tangent-bitangent-normal frame with the tangent, bitangent,
+    /// and normal as the x, y, and z axes. The tangent is made orthogonal to
+    /// the normal, and the bitangent is `normal × tangent` times the sign of
+    /// `handedness`, so a negative handedness (mirrored UVs) gives a
+    /// left-handed frame.
+    pub fn tbn(position: Vec3, normal: Vec3, tangent: Vec3, handedness: Float) -> Self {
+        let n = normal.unit();
+        let t = (tangent - n * tangent.dot(n)).unit();
+        let b = n.cross(t) * handedness.signum();
+        Self::from_columns(t, b, n, position)
+    }
+
     pub fn from_pos_facing(from: Vec3, to: Vec3) -> Self {
-        let mut z = (from - to).unit();
-        let mut x = Vec3::up().cross(z);
-        let mut y = z.cross(x);
-        if x.magnitude() == 0.0 {
+        let z = (from - to).unit();
+        let x = Vec3::up().cross(z);
+        let (x, y, z) = if x.approx_zero(1e-6) {
             if z.y < 0.0 {
-                x = Vec3::forward();
-                y = Vec3::right();
-                z = Vec3::down();
+                (Vec3::forward(), Vec3::right(), Vec3::down())
             } else {
-                x = Vec3::backward();
-                y = Vec3::right();
-                z = Vec3::up();
+                (Vec3::backward(), Vec3::right(), Vec3::up())
             }
+        } else {
+            let x = x.unit();
+            (x, z.cross(x), z)
+        };
+        Self::from_columns(x, y, z, from)
+    }
+
+    /// Like `from_pos_facing`, but uses `primary_up` to resolve roll and only
+    /// switches to `fallback_up` when the look direction is nearly parallel
+    /// to `primary_up`.
+    pub fn from_pos_facing_with_up(
+        from: Vec3,
+        to: Vec3,
+        primary_up: Vec3,
+        fallback_up: Vec3,
+    ) -> Self {
+        let z = (from - to).unit();
+        if z.is_zero() {
+            return Self::from_pos(from);
         }
-        Self {
-            r11: x.x,
-            r12: y.x,
-            r13: z.x,
-            r14: from.x,
-            r21: x.y,
-            r22: y.y,
-            r23: z.y,
-            r24: from.y,
-            r31: x.z,
-            r32: y.z,
-            r33: z.z,
-            r34: from.z,
+        let mut x = primary_up.unit().cross(z);
+        if x.approx_zero(1e-6) {
+            x = fallback_up.unit().cross(z);
         }
+        let x = x.unit();
+        let y = z.cross(x);
+        Self::from_columns(x, y, z, from)
     }
 
+    /// Builds a view matrix for a camera at `eye` looking at `center`, with
+    /// world up as the hint. The camera axes are stored as the rows of the
+    /// rotation, so the result maps world space into camera space.
+    ///
+    /// Earlier versions stored the axes as columns and returned the identity
+    /// when looking straight up or down; this now falls back to another
+    /// world axis for up, as `look_at_up` does.
     pub fn look_at(eye: Vec3, center: Vec3) -> Self {
-        if (eye - center).magnitude() == 0.0 {
+        Self::look_at_up(eye, center, Vec3::up())
+    }
+
+    /// Like `look_at`, but with an explicit `up` hint. When the look
+    /// direction is parallel to `up`, another world axis is used instead.
+    pub fn look_at_up(eye: Vec3, center: Vec3, up: Vec3) -> Self {
+        if (eye - center).is_zero() {
             return CFrame::identity();
         }
         let z = (eye - center).unit();
-        let x = Vec3::up().cross(z).unit();
-        if x.magnitude() == 0.0 {
-            return CFrame::identity();
+        let mut x = up.unit().cross(z);
+        if x.approx_zero(1e-6) {
+            x = Self::non_parallel_axis(z).cross(z);
         }
+        let x = x.unit();
         let y = z.cross(x);
-        return CFrame {
+        CFrame {
             r11: x.x,
-            r12: y.x,
-            r13: z.x,
+            r12: x.y,
+            r13: x.z,
             r14: -(x.x * eye.x + x.y * eye.y + x.z * eye.z),
-            r21: x.y,
+            r21: y.x,
             r22: y.y,
-            r23: z.y,
+            r23: y.z,
             r24: -(y.x * eye.x + y.y * eye.y + y.z * eye.z),
-            r31: x.z,
-            r32: y.z,
+            r31: z.x,
+            r32: z.y,
             r33: z.z,
             r34: -(z.x * eye.x + z.y * eye.y + z.z * eye.z),
-        };
+        }
+    }
+
+    /// An object frame at `eye` with -Z pointing at `center`, using `up` to
+    /// resolve roll. Unlike `look_at`, this is a model transform, not a view
+    /// matrix, so `p()` is `eye`. If the look direction is parallel to `up`,
+    /// another world axis is used as the up hint instead.
+    pub fn look_at_model(eye: Vec3, center: Vec3, up: Vec3) -> Self {
+        Self::from_pos_facing_with_up(eye, center, up, Self::non_parallel_axis(up))
+    }
+
+    /// A frame at `pos` with -Z along the direction `look`, using `up` to
+    /// resolve roll. Like `look_at_model`, it falls back to another world
+    /// axis when `look` is parallel to `up`.
+    pub fn from_pos_look_up(pos: Vec3, look: Vec3, up: Vec3) -> Self {
+        Self::look_at_model(pos, pos + look, up)
+    }
+
+    /// A world axis that is not parallel to `v`.
+    fn non_parallel_axis(v: Vec3) -> Vec3 {
+        if v.unit().x.abs() < 0.9 {
+            Vec3::right()
+        } else {
+            Vec3::up()
+        }
     }
 
     pub fn from_pos(pos: Vec3) -> Self {
@@ -180,15 +374,15 @@ impl CFrame {
         let m14 = pos.x;
         let m24 = pos.y;
         let m34 = pos.z;
-        let m11 = 1.0 - 2.0 * (j * j - k * k);
+        let m11 = 1.0 - 2.0 * (j * j + k * k);
         let m12 = 2.0 * (i * j - k * w);
         let m13 = 2.0 * (i * k + j * w);
         let m21 = 2.0 * (i * j + k * w);
-        let m22 = 1.0 - 2.0 * (i * i - k * k);
+        let m22 = 1.0 - 2.0 * (i * i + k * k);
         let m23 = 2.0 * (j * k - i * w);
         let m31 = 2.0 * (i * k - j * w);
         let m32 = 2.0 * (j * k + i * w);
-        let m33 = 1.0 - 2.0 * (i * i - j * j);
+        let m33 = 1.0 - 2.0 * (i * i + j * j);
 
         Self {
             r11: m11,
@@ -207,10 +401,10 @@ impl CFrame {
     }
 
     pub fn from_axis_angle(axis: Vec3, theta: Float) -> Self {
-        let r: Vec3 = Self::vec_axis_angle(axis, Vec3::right(), theta);
-        let u: Vec3 = Self::vec_axis_angle(axis, Vec3::up(), theta);
-        let b: Vec3 = Self::vec_axis_angle(axis, Vec3::backward(), theta);
-        return Self {
+        let r: Vec3 = Vec3::right().rotate_around_axis(axis, theta);
+        let u: Vec3 = Vec3::up().rotate_around_axis(axis, theta);
+        let b: Vec3 = Vec3::backward().rotate_around_axis(axis, theta);
+        Self {
             r11: r.x,
             r12: u.x,
             r13: b.x,
@@ -223,15 +417,41 @@ impl CFrame {
             r32: u.z,
             r33: b.z,
             r34: 0.0,
-        };
+        }
+    }
+
+    /// Rotation about X by `rx`, then Y by `ry`, then Z by `rz`, composed as
+    /// `Rx * Ry * Rz` to match Roblox's `CFrame.Angles`.
+    pub fn angles(rx: Float, ry: Float, rz: Float) -> Self {
+        Self::from_axis_angle(Vec3::right(), rx)
+            * Self::from_axis_angle(Vec3::up(), ry)
+            * Self::from_axis_angle(Vec3::backward(), rz)
     }
 
-    fn vec_axis_angle(mut n: Vec3, v: Vec3, t: Float) -> Vec3 {
-        n = n.unit();
-        let u = t.cos();
-        return v * u + n * v.dot(n) * (1.0 - u) + n.cross(v) * t.sin();
+    /// The shortest-arc rotation taking the direction of `from` onto the
+    /// direction of `to`, with no translation. For opposite directions any
+    /// perpendicular axis is used for the half turn.
+    pub fn from_rotation_between(from: Vec3, to: Vec3) -> Self {
+        let a = from.unit();
+        let b = to.unit();
+        let axis = a.cross(b);
+        let sin = axis.magnitude();
+        let cos = a.dot(b);
+        if sin < 1e-6 {
+            if cos >= 0.0 {
+                return Self::identity();
+            }
+            let mut perpendicular = a.cross(Vec3::right());
+            if perpendicular.approx_zero(1e-6) {
+                perpendicular = a.cross(Vec3::up());
+            }
+            return Self::from_axis_angle(perpendicular, PI);
+        }
+        Self::from_axis_angle(axis, sin.atan2(cos))
     }
 
+    /// A right-handed OpenGL-style perspective projection in column-major
+    /// order: the camera looks down -Z and depth maps to `[-1, 1]`.
     pub fn perspective(fov: Float, aspect: Float, near: Float, far: Float) -> [Float; 16] {
         let f = 1.0 / (fov / 2.0).tan();
         let c00 = f / aspect;
@@ -255,6 +475,86 @@ impl CFrame {
         ]
     }
 
+    /// The left-handed counterpart of `perspective`: the camera looks down
+    /// +Z, and depth still maps to `[-1, 1]` from near to far.
+    pub fn perspective_lh(fov: Float, aspect: Float, near: Float, far: Float) -> [Float; 16] {
+        let f = 1.0 / (fov / 2.0).tan();
+        let c00 = f / aspect;
+        let c11 = f;
+        let c22 = (far + near) / (far - near);
+        let c23 = 1.0;
+        let c32 = -(2.0 * far * near) / (far - near);
+        [
+            c00, 0.0, 0.0, 0.0, 0.0, c11, 0.0, 0.0, 0.0, 0.0, c22, c23, 0.0, 0.0, c32, 0.0,
+        ]
+    }
+
+    /// Like `perspective`, but depth maps to `[0, 1]` instead of `[-1, 1]`,
+    /// matching the clip space of Vulkan, WebGPU, and Direct3D (OpenGL's
+    /// `GL_ZERO_TO_ONE`). Unlike `perspective`, near maps to 0 rather than -1.
+    pub fn perspective_zo(fov: Float, aspect: Float, near: Float, far: Float) -> [Float; 16] {
+        let f = 1.0 / (fov / 2.0).tan();
+        let c00 = f / aspect;
+        let c11 = f;
+        let c22 = far / (near - far);
+        let c23 = -1.0;
+        let c32 = (far * near) / (near - far);
+        [
+            c00, 0.0, 0.0, 0.0, 0.0, c11, 0.0, 0.0, 0.0, 0.0, c22, c23, 0.0, 0.0, c32, 0.0,
+        ]
+    }
+
+    /// A reversed-Z variant of `perspective_zo`: near maps to 1 and far to 0,
+    /// which spreads floating-point depth precision much more evenly. Pass
+    /// `Float::INFINITY` as `far` for an infinite far plane. Pair it with a
+    /// `GREATER` depth test and a depth clear value of 0.
+    pub fn perspective_reverse_z(
+        fov: Float,
+        aspect: Float,
+        near: Float,
+        far: Float,
+    ) -> [Float; 16] {
+        let f = 1.0 / (fov / 2.0).tan();
+        let c00 = f / aspect;
+        let c11 = f;
+        let (c22, c32) = if far.is_infinite() {
+            (0.0, near)
+        } else {
+            (near / (far - near), (far * near) / (far - near))
+        };
+        let c23 = -1.0;
+        [
+            c00, 0.0, 0.0, 0.0, 0.0, c11, 0.0, 0.0, 0.0, 0.0, c22, c23, 0.0, 0.0, c32, 0.0,
+        ]
+    }
+
+    /// An OpenGL-style orthographic projection in the same column-major
+    /// layout as `perspective`, mapping the box to `[-1, 1]` on every axis.
+    /// Assumes `left != right`, `bottom != top`, and `near != far`; an empty
+    /// range doesn't panic but fills the matrix with infinities or NaN.
+    pub fn orthographic(
+        left: Float,
+        right: Float,
+        bottom: Float,
+        top: Float,
+        near: Float,
+        far: Float,
+    ) -> [Float; 16] {
+        let c00 = 2.0 / (right - left);
+        let c11 = 2.0 / (top - bottom);
+        let c22 = -2.0 / (far - near);
+        let c30 = -(right + left) / (right - left);
+        let c31 = -(top + bottom) / (top - bottom);
+        let c32 = -(far + near) / (far - near);
+        [
+            c00, 0.0, 0.0, 0.0, 0.0, c11, 0.0, 0.0, 0.0, 0.0, c22, 0.0, c30, c31, c32, 1.0,
+        ]
+    }
+
+    /// The frame as a 4x4 matrix in column-major order: each run of four
+    /// values is one column, and the translation is at indices 12, 13, and
+    /// 14. This is what OpenGL, Vulkan, and WebGPU expect by default, and it
+    /// matches `perspective` and `orthographic`.
     pub fn to_array(&self) -> [Float; 16] {
         [
             self.r11, self.r21, self.r31, 0.0, self.r12, self.r22, self.r32, 0.0, self.r13,
@@ -262,12 +562,72 @@ impl CFrame {
         ]
     }
 
+    /// The same matrix in row-major order: each run of four values is one
+    /// row, and the translation is at indices 3, 7, and 11. Use this for
+    /// Direct3D-style code and anything else that stores matrices row by row
+    /// while still multiplying column vectors.
+    pub fn to_array_row_major(&self) -> [Float; 16] {
+        [
+            self.r11, self.r12, self.r13, self.r14, self.r21, self.r22, self.r23, self.r24,
+            self.r31, self.r32, self.r33, self.r34, 0.0, 0.0, 0.0, 1.0,
+        ]
+    }
+
+    /// Reads a column-major matrix in the layout `to_array` produces. The
+    /// bottom (projective) row is ignored.
+    pub fn from_matrix(m: [Float; 16]) -> Self {
+        Self {
+            r11: m[0],
+            r12: m[4],
+            r13: m[8],
+            r14: m[12],
+            r21: m[1],
+            r22: m[5],
+            r23: m[9],
+            r24: m[13],
+            r31: m[2],
+            r32: m[6],
+            r33: m[10],
+            r34: m[14],
+        }
+    }
+
+    /// `to_array` cast to `f32` for GPU upload, whatever the crate's `Float`.
+    #[allow(clippy::unnecessary_cast)]
+    pub fn to_array_f32(&self) -> [f32; 16] {
+        self.to_array().map(|v| v as f32)
+    }
+
+    /// The transpose of `to_array`; the same as `to_array_row_major`.
+    pub fn transposed_array(&self) -> [Float; 16] {
+        self.to_array_row_major()
+    }
+
     pub fn determinant(&self) -> Float {
         self.r11 * (self.r22 * self.r33 - self.r32 * self.r23)
             - self.r21 * (self.r12 * self.r33 - self.r32 * self.r13)
             + self.r31 * (self.r12 * self.r23 - self.r22 * self.r13)
     }
 
+    /// Whether the basis columns are unit length and mutually perpendicular
+    /// within `epsilon`, with a determinant near +1 (no reflection).
+    pub fn is_orthonormal(&self, epsilon: Float) -> bool {
+        let (x, y, z) = (self.x(), self.y(), self.z());
+        (x.magnitude() - 1.0).abs() <= epsilon
+            && (y.magnitude() - 1.0).abs() <= epsilon
+            && (z.magnitude() - 1.0).abs() <= epsilon
+            && x.dot(y).abs() <= epsilon
+            && y.dot(z).abs() <= epsilon
+            && z.dot(x).abs() <= epsilon
+            && (self.determinant() - 1.0).abs() <= epsilon
+    }
+
+    /// Whether the basis has a positive determinant. A negative one means the
+    /// frame is mirrored, e.g. from a flipped cross product or swapped axes.
+    pub fn is_right_handed(&self) -> bool {
+        self.determinant() > 0.0
+    }
+
     pub fn inverse(&self) -> CFrame {
         let det = self.determinant();
         if det == 0.0 {
@@ -275,30 +635,18 @@ impl CFrame {
         }
         let inv_det = 1.0 / det;
         let m11 = (self.r22 * self.r33 - self.r32 * self.r23) * inv_det;
-        let m12 = (self.r31 * self.r23 - self.r21 * self.r33) * inv_det;
-        let m13 = (self.r21 * self.r32 - self.r31 * self.r22) * inv_det;
-        let m14 = (self.r21 * (self.r32 * self.r34 - self.r33 * self.r24)
-            + self.r31 * (self.r23 * self.r24 - self.r22 * self.r34)
-            + self.r22 * self.r33
-            - self.r32 * self.r23)
-            * inv_det;
-        let m21 = (self.r32 * self.r13 - self.r12 * self.r33) * inv_det;
+        let m12 = (self.r32 * self.r13 - self.r12 * self.r33) * inv_det;
+        let m13 = (self.r12 * self.r23 - self.r22 * self.r13) * inv_det;
+        let m21 = (self.r31 * self.r23 - self.r21 * self.r33) * inv_det;
         let m22 = (self.r11 * self.r33 - self.r31 * self.r13) * inv_det;
-        let m23 = (self.r31 * self.r12 - self.r11 * self.r32) * inv_det;
-        let m24 = (self.r31 * (self.r12 * self.r34 - self.r13 * self.r24)
-            + self.r11 * (self.r23 * self.r24 - self.r22 * self.r34)
-            + self.r12 * self.r33
-            - self.r32 * self.r13)
-            * inv_det;
-        let m31 = (self.r12 * self.r23 - self.r22 * self.r13) * inv_det;
-        let m32 = (self.r21 * self.r13 - self.r11 * self.r23) * inv_det;
+        let m23 = (self.r21 * self.r13 - self.r11 * self.r23) * inv_det;
+        let m31 = (self.r21 * self.r32 - self.r31 * self.r22) * inv_det;
+        let m32 = (self.r31 * self.r12 - self.r11 * self.r32) * inv_det;
         let m33 = (self.r11 * self.r22 - self.r21 * self.r12) * inv_det;
-        let m34 = (self.r11 * (self.r22 * self.r34 - self.r23 * self.r24)
-            + self.r21 * (self.r13 * self.r24 - self.r12 * self.r34)
-            + self.r12 * self.r23
-            - self.r22 * self.r13)
-            * inv_det;
-        return CFrame {
+        let m14 = -(m11 * self.r14 + m12 * self.r24 + m13 * self.r34);
+        let m24 = -(m21 * self.r14 + m22 * self.r24 + m23 * self.r34);
+        let m34 = -(m31 * self.r14 + m32 * self.r24 + m33 * self.r34);
+        CFrame {
             r11: m11,
             r12: m12,
             r13: m13,
@@ -311,10 +659,489 @@ impl CFrame {
             r32: m32,
             r33: m33,
             r34: m34,
+        }
+    }
+
+    /// The inverse of a rigid frame: the transposed rotation with translation
+    /// `-(Rᵀ p)`. Much cheaper than `inverse()`, but the result is garbage if
+    /// the frame isn't orthonormal (see `is_orthonormal`).
+    pub fn orthonormal_inverse(&self) -> CFrame {
+        let p = self.vector_to_object_space(self.p());
+        self.transpose_rotation().with_position(-p)
+    }
+
+    /// Restores an orthonormal right-handed rotation with Gram-Schmidt,
+    /// keeping the direction of x and the plane of x and y. Position is kept.
+    pub fn orthonormalize(&self) -> CFrame {
+        self.orthonormalize_preserving(Axis::X)
+    }
+
+    pub fn point_to_world_space(&self, v: Vec3) -> Vec3 {
+        *self * v
+    }
+
+    /// Applies `point_to_world_space` to every point in place.
+    pub fn transform_points(&self, points: &mut [Vec3]) {
+        for point in points {
+            *point = self.point_to_world_space(*point);
+        }
+    }
+
+    /// Applies `vector_to_world_space` to every vector in place, rotating
+    /// without translating.
+    pub fn transform_vectors(&self, vectors: &mut [Vec3]) {
+        for vector in vectors {
+            *vector = self.vector_to_world_space(*vector);
+        }
+    }
+
+    /// The inverse of `point_to_world_space`. Uses the transposed rotation,
+    /// so it assumes the frame is orthonormal; use `inverse()` for scaled
+    /// frames.
+    pub fn point_to_object_space(&self, v: Vec3) -> Vec3 {
+        self.vector_to_object_space(v - self.p())
+    }
+
+    /// Rotates a direction into world space without applying translation.
+    pub fn vector_to_world_space(&self, v: Vec3) -> Vec3 {
+        Vec3::new(
+            self.r11 * v.x + self.r12 * v.y + self.r13 * v.z,
+            self.r21 * v.x + self.r22 * v.y + self.r23 * v.z,
+            self.r31 * v.x + self.r32 * v.y + self.r33 * v.z,
+        )
+    }
+
+    /// The inverse of `vector_to_world_space`, using the transposed rotation.
+    pub fn vector_to_object_space(&self, v: Vec3) -> Vec3 {
+        Vec3::new(self.x().dot(v), self.y().dot(v), self.z().dot(v))
+    }
+
+    /// Transposes the 3x3 rotation block and keeps the position as is. For
+    /// an orthonormal frame this inverts the rotation; unlike `inverse()`, it
+    /// never touches the translation.
+    pub fn transpose_rotation(&self) -> CFrame {
+        CFrame {
+            r11: self.r11,
+            r12: self.r21,
+            r13: self.r31,
+            r14: self.r14,
+            r21: self.r12,
+            r22: self.r22,
+            r23: self.r32,
+            r24: self.r24,
+            r31: self.r13,
+            r32: self.r23,
+            r33: self.r33,
+            r34: self.r34,
+        }
+    }
+
+    /// The rotation as a unit quaternion `(i, j, k, w)`, the inverse of
+    /// `from_pos_quaternions`. Uses Shepperd's method, branching on the
+    /// largest diagonal term so it never divides by a small number.
+    pub fn to_quaternion(&self) -> (Float, Float, Float, Float) {
+        let trace = self.r11 + self.r22 + self.r33;
+        let (i, j, k, w) = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            (
+                (self.r32 - self.r23) / s,
+                (self.r13 - self.r31) / s,
+                (self.r21 - self.r12) / s,
+                s / 4.0,
+            )
+        } else if self.r11 > self.r22 && self.r11 > self.r33 {
+            let s = (1.0 + self.r11 - self.r22 - self.r33).sqrt() * 2.0;
+            (
+                s / 4.0,
+                (self.r12 + self.r21) / s,
+                (self.r13 + self.r31) / s,
+                (self.r32 - self.r23) / s,
+            )
+        } else if self.r22 > self.r33 {
+            let s = (1.0 + self.r22 - self.r11 - self.r33).sqrt() * 2.0;
+            (
+                (self.r12 + self.r21) / s,
+                s / 4.0,
+                (self.r23 + self.r32) / s,
+                (self.r13 - self.r31) / s,
+            )
+        } else {
+            let s = (1.0 + self.r33 - self.r11 - self.r22).sqrt() * 2.0;
+            (
+                (self.r13 + self.r31) / s,
+                (self.r23 + self.r32) / s,
+                s / 4.0,
+                (self.r21 - self.r12) / s,
+            )
+        };
+        let inv_mag = 1.0 / (i * i + j * j + k * k + w * w).sqrt();
+        (i * inv_mag, j * inv_mag, k * inv_mag, w * inv_mag)
+    }
+
+    /// The rotation as a unit axis and an angle in `[0, π]` radians, such
+    /// that `from_axis_angle(axis, angle)` reproduces it. Extracted through
+    /// the quaternion, so it stays accurate near 0 and π. With no rotation
+    /// the axis is `Vec3::up()` and the angle is 0.
+    pub fn to_axis_angle(&self) -> (Vec3, Float) {
+        let (i, j, k, w) = self.to_quaternion();
+        let v = if w < 0.0 {
+            -Vec3::new(i, j, k)
+        } else {
+            Vec3::new(i, j, k)
+        };
+        let s = v.magnitude();
+        if s == 0.0 {
+            return (Vec3::up(), 0.0);
+        }
+        (v / s, 2.0 * s.atan2(w.abs()))
+    }
+
+    pub(crate) fn rotation_vector(&self) -> Vec3 {
+        let (axis, angle) = self.to_axis_angle();
+        axis * angle
+    }
+
+    /// True if all twelve components are within `epsilon` of `other`'s.
+    pub fn approx_eq(&self, other: &CFrame, epsilon: Float) -> bool {
+        self.to_array()
+            .iter()
+            .zip(other.to_array())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+
+    /// Angle in radians of the rotation taking this frame's orientation to
+    /// `other`'s. Translation is ignored.
+    pub fn angle_to(&self, other: CFrame) -> Float {
+        let trace = self.x().dot(other.x()) + self.y().dot(other.y()) + self.z().dot(other.z());
+        ((trace - 1.0) / 2.0).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Distance between the two frames' positions.
+    pub fn position_distance(&self, other: &CFrame) -> Float {
+        self.p().distance(other.p())
+    }
+
+    /// Like `angle_to`, but read off the axis-angle of the relative rotation,
+    /// which stays accurate for very small angles where `acos` does not.
+    pub fn angular_distance(&self, other: &CFrame) -> Float {
+        (self.rotation().transpose_rotation() * other.rotation())
+            .to_axis_angle()
+            .1
+    }
+
+    /// This frame's position expressed in `reference`'s object space.
+    pub fn position_in(&self, reference: CFrame) -> Vec3 {
+        reference.point_to_object_space(self.p())
+    }
+
+    /// This frame expressed in `parent`'s local space, `parent.inverse() *
+    /// self`. The inverse of `to_world_space`.
+    pub fn relative_to(&self, parent: &CFrame) -> CFrame {
+        parent.inverse() * *self
+    }
+
+    /// Treats this frame as local to `parent` and returns it in world space,
+    /// `parent * self`.
+    pub fn to_world_space(&self, parent: &CFrame) -> CFrame {
+        *parent * *self
+    }
+
+    /// Applies a body-frame rotation vector (axis times angle, e.g. angular
+    /// velocity times the time step) using the exact exponential map, then
+    /// re-orthonormalizes the rotation to keep drift from building up.
+    /// Translation is kept.
+    pub fn rotate_by_angular_delta(&self, delta: Vec3) -> CFrame {
+        (*self * CFrame::from_axis_angle(delta, delta.magnitude())).orthonormalize()
+    }
+
+    pub fn to_dual_quaternion(&self) -> DualQuat {
+        let (i, j, k, w) = self.to_quaternion();
+        let real = [i, j, k, w];
+        let t = [self.r14, self.r24, self.r34, 0.0];
+        DualQuat::new(real, quat_mul(t, real).map(|c| c * 0.5))
+    }
+
+    /// Converts a dual quaternion back to a frame, normalizing it first.
+    pub fn from_dual_quaternion(dq: DualQuat) -> Self {
+        let dq = dq.normalize();
+        let [i, j, k, w] = dq.real;
+        let t = quat_mul(dq.dual, [-i, -j, -k, w]);
+        Self::from_pos_quaternions(Vec3::new(t[0], t[1], t[2]) * 2.0, i, j, k, w)
+    }
+
+    /// The min and max corners of `points` measured in this frame's object
+    /// space, i.e. the extents of an oriented box aligned with the frame's
+    /// axes. Returns two zero vectors for an empty slice.
+    pub fn local_aabb_of(&self, points: &[Vec3]) -> (Vec3, Vec3) {
+        let mut local = points.iter().map(|v| self.point_to_object_space(*v));
+        let Some(first) = local.next() else {
+            return (Vec3::zero(), Vec3::zero());
+        };
+        local.fold((first, first), |(min, max), v| (min.min(v), max.max(v)))
+    }
+
+    /// Re-orthonormalizes the rotation while keeping the direction of the
+    /// `primary` axis fixed; the other two axes are rebuilt around it in
+    /// right-handed order. Position is kept.
+    pub fn orthonormalize_preserving(&self, primary: Axis) -> CFrame {
+        let (x, y, z) = match primary {
+            Axis::X => {
+                let x = self.x().unit();
+                let z = x.cross(self.y()).unit();
+                (x, z.cross(x), z)
+            }
+            Axis::Y => {
+                let y = self.y().unit();
+                let x = y.cross(self.z()).unit();
+                (x, y, x.cross(y))
+            }
+            Axis::Z => {
+                let z = self.z().unit();
+                let y = z.cross(self.x()).unit();
+                (y.cross(z), y, z)
+            }
+        };
+        CFrame::from_columns(x, y, z, self.p())
+    }
+
+    /// Returns `(rx, ry, rz)` in radians such that `CFrame::angles(rx, ry, rz)`
+    /// reproduces the rotation, matching Roblox's `CFrame:ToEulerAnglesXYZ`.
+    /// In gimbal lock (`ry` at ±π/2) `rz` is reported as zero.
+    pub fn to_euler_angles_xyz(&self) -> (Float, Float, Float) {
+        let ry = self.r13.clamp(-1.0, 1.0).asin();
+        if self.r13.abs() < 1.0 - 1e-9 {
+            let rx = (-self.r23).atan2(self.r33);
+            let rz = (-self.r12).atan2(self.r11);
+            (rx, ry, rz)
+        } else {
+            let rx = self.r32.atan2(self.r22);
+            (rx, ry, 0.0)
+        }
+    }
+
+    /// Interpolates all twelve components linearly, then re-orthonormalizes
+    /// the rotation. Cheaper than `slerp` but doesn't move at a constant
+    /// angular rate. `t` is not clamped, so values outside `[0, 1]`
+    /// extrapolate.
+    pub fn lerp(&self, other: &CFrame, t: Float) -> CFrame {
+        let mix = |a: Float, b: Float| a + t * (b - a);
+        CFrame {
+            r11: mix(self.r11, other.r11),
+            r12: mix(self.r12, other.r12),
+            r13: mix(self.r13, other.r13),
+            r14: mix(self.r14, other.r14),
+            r21: mix(self.r21, other.r21),
+            r22: mix(self.r22, other.r22),
+            r23: mix(self.r23, other.r23),
+            r24: mix(self.r24, other.r24),
+            r31: mix(self.r31, other.r31),
+            r32: mix(self.r32, other.r32),
+            r33: mix(self.r33, other.r33),
+            r34: mix(self.r34, other.r34),
+        }
+        .orthonormalize()
+    }
+
+    /// Interpolates position linearly and rotation along the shortest arc
+    /// between the two orientations. Returns exactly `self` at `t = 0` and
+    /// `other` at `t = 1`.
+    pub fn slerp(&self, other: &CFrame, t: Float) -> CFrame {
+        if t == 0.0 {
+            return *self;
+        }
+        if t == 1.0 {
+            return *other;
+        }
+        let (ai, aj, ak, aw) = self.to_quaternion();
+        let (mut bi, mut bj, mut bk, mut bw) = other.to_quaternion();
+        let mut dot = ai * bi + aj * bj + ak * bk + aw * bw;
+        if dot < 0.0 {
+            (bi, bj, bk, bw) = (-bi, -bj, -bk, -bw);
+            dot = -dot;
+        }
+        let (wa, wb) = if dot > 0.9995 {
+            (1.0 - t, t)
+        } else {
+            let theta = dot.acos();
+            let sin_theta = theta.sin();
+            (
+                ((1.0 - t) * theta).sin() / sin_theta,
+                (t * theta).sin() / sin_theta,
+            )
+        };
+        let (i, j, k, w) = (
+            ai * wa + bi * wb,
+            aj * wa + bj * wb,
+            ak * wa + bk * wb,
+            aw * wa + bw * wb,
+        );
+        let inv_mag = 1.0 / (i * i + j * j + k * k + w * w).sqrt();
+        CFrame::from_pos_quaternions(
+            self.p().lerp(other.p(), t),
+            i * inv_mag,
+            j * inv_mag,
+            k * inv_mag,
+            w * inv_mag,
+        )
+    }
+
+    /// Like `slerp`, but with separate parameters for position (`pos_t`,
+    /// linear) and rotation (`rot_t`, shortest arc), so translation and
+    /// rotation can follow at different rates.
+    pub fn blend(&self, other: &CFrame, pos_t: Float, rot_t: Float) -> CFrame {
+        self.slerp(other, rot_t)
+            .with_position(self.p().lerp(other.p(), pos_t))
+    }
+
+    /// Packs the frame as `[px, py, pz, qx, qy, qz, qw]`, with the quaternion
+    /// in the same `(i, j, k, w)` order as `to_quaternion`.
+    pub fn to_instance_data(&self) -> [Float; 7] {
+        let (i, j, k, w) = self.to_quaternion();
+        [self.r14, self.r24, self.r34, i, j, k, w]
+    }
+
+    pub fn from_instance_data(data: [Float; 7]) -> Self {
+        let [px, py, pz, i, j, k, w] = data;
+        Self::from_pos_quaternions(Vec3::new(px, py, pz), i, j, k, w)
+    }
+
+    /// Splits the orientation into a heading about the world Y axis and the
+    /// remaining rotation, so that
+    /// `CFrame::from_axis_angle(Vec3::up(), yaw) * residual` reproduces the
+    /// orientation. The residual has no translation. The heading follows the
+    /// z axis, or the x axis when z points straight up or down.
+    pub fn split_yaw(&self) -> (Float, CFrame) {
+        let yaw = if (self.r13 * self.r13 + self.r33 * self.r33).sqrt() < 1e-6 {
+            (-self.r31).atan2(self.r11)
+        } else {
+            self.r13.atan2(self.r33)
         };
+        let residual = CFrame::from_axis_angle(Vec3::up(), -yaw) * self.rotation();
+        (yaw, residual)
+    }
+
+    /// Returns `(position_distance, rotation_angle)` between the two frames.
+    pub fn difference(&self, other: CFrame) -> (Float, Float) {
+        (self.p().distance(other.p()), self.angle_to(other))
+    }
+
+    /// `count` world-space points evenly spaced on a circle of `radius` in
+    /// the frame's local XY plane, starting on the local x axis.
+    pub fn points_on_local_circle(&self, radius: Float, count: usize) -> Vec<Vec3> {
+        let (x, y, p) = (self.x(), self.y(), self.p());
+        (0..count)
+            .map(|i| {
+                let theta = TAU * i as Float / count as Float;
+                p + x * (radius * theta.cos()) + y * (radius * theta.sin())
+            })
+            .collect()
+    }
+
+    /// `count` world-space points spread evenly over a sphere of `radius`
+    /// around the frame's position, using a Fibonacci lattice so they don't
+    /// bunch up at the poles. The lattice runs from the local +y pole to -y.
+    pub fn points_on_local_sphere(&self, radius: Float, count: usize) -> Vec<Vec3> {
+        let (x, y, z, p) = (self.x(), self.y(), self.z(), self.p());
+        let golden_angle = PI * (3.0 - (5.0 as Float).sqrt());
+        (0..count)
+            .map(|i| {
+                let h = 1.0 - 2.0 * (i as Float + 0.5) / count as Float;
+                let r = (1.0 - h * h).sqrt();
+                let phi = golden_angle * i as Float;
+                p + (x * (r * phi.cos()) + y * h + z * (r * phi.sin())) * radius
+            })
+            .collect()
     }
 }
 
+/// The rotation that best maps each `from[i]` onto `to[i]` in the weighted
+/// least-squares sense (Wahba's problem), solved with Horn's quaternion
+/// method. Weights default to 1. The result has no translation.
+///
+/// Panics if `from`, `to`, and `weights` differ in length.
+pub fn best_fit_rotation(from: &[Vec3], to: &[Vec3], weights: Option<&[Float]>) -> CFrame {
+    assert_eq!(
+        from.len(),
+        to.len(),
+        "from and to must have the same length"
+    );
+    if let Some(weights) = weights {
+        assert_eq!(
+            weights.len(),
+            from.len(),
+            "weights must match the number of vectors"
+        );
+    }
+    let mut s = [[0.0; 3]; 3];
+    for (index, (a, b)) in from.iter().zip(to).enumerate() {
+        let w = weights.map_or(1.0, |weights| weights[index]);
+        let a = [a.x, a.y, a.z];
+        let b = [b.x, b.y, b.z];
+        for row in 0..3 {
+            for col in 0..3 {
+                s[row][col] += w * a[row] * b[col];
+            }
+        }
+    }
+    let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = s;
+    let n = [
+        [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+        [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+        [szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy],
+        [sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz],
+    ];
+    let [w, i, j, k] = max_eigenvector(n);
+    let inv_mag = 1.0 / (w * w + i * i + j * j + k * k).sqrt();
+    CFrame::from_pos_quaternions(
+        Vec3::zero(),
+        i * inv_mag,
+        j * inv_mag,
+        k * inv_mag,
+        w * inv_mag,
+    )
+}
+
+fn max_eigenvector(mut a: [[Float; 4]; 4]) -> [Float; 4] {
+    const PAIRS: [(usize, usize); 6] = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+    let mut v = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    for _ in 0..50 {
+        let off: Float = PAIRS.iter().map(|&(p, q)| a[p][q] * a[p][q]).sum();
+        if off < 1e-30 {
+            break;
+        }
+        for (p, q) in PAIRS {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            for row in a.iter_mut().chain(v.iter_mut()) {
+                let (rp, rq) = (row[p], row[q]);
+                row[p] = c * rp - s * rq;
+                row[q] = s * rp + c * rq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+        }
+    }
+    let best = (1..4).fold(0, |best, index| {
+        if a[index][index] > a[best][best] {
+            index
+        } else {
+            best
+        }
+    });
+    [v[0][best], v[1][best], v[2][best], v[3][best]]
+}
+
 impl Add<Vec3> for CFrame {
     type Output = CFrame;
 
@@ -371,6 +1198,19 @@ impl SubAssign<Vec3> for CFrame {
     }
 }
 
+/// The offset between the two positions, `self.p() - rhs.p()`. Rotation is
+/// ignored entirely.
+impl Sub<CFrame> for CFrame {
+    type Output = Vec3;
+
+    fn sub(self, rhs: CFrame) -> Vec3 {
+        self.p() - rhs.p()
+    }
+}
+
+/// Composes two frames so that `(a * b) * v == a * (b * v)`. This is a full
+/// affine matrix product, so it is also correct for frames carrying scale
+/// (see `scaled`), not just rigid ones.
 impl Mul for CFrame {
     type Output = CFrame;
 
@@ -391,12 +1231,49 @@ impl Mul for CFrame {
         )
     }
 }
+
+impl Mul<&CFrame> for &CFrame {
+    type Output = CFrame;
+
+    fn mul(self, rhs: &CFrame) -> CFrame {
+        *self * *rhs
+    }
+}
+
+impl Mul<&CFrame> for CFrame {
+    type Output = CFrame;
+
+    fn mul(self, rhs: &CFrame) -> CFrame {
+        self * *rhs
+    }
+}
+
+impl Mul<CFrame> for &CFrame {
+    type Output = CFrame;
+
+    fn mul(self, rhs: CFrame) -> CFrame {
+        *self * rhs
+    }
+}
+
 impl MulAssign for CFrame {
     fn mul_assign(&mut self, rhs: CFrame) {
         *self = *self * rhs;
     }
 }
 
+impl Mul<Vec3> for CFrame {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(
+            self.r11 * rhs.x + self.r12 * rhs.y + self.r13 * rhs.z + self.r14,
+            self.r21 * rhs.x + self.r22 * rhs.y + self.r23 * rhs.z + self.r24,
+            self.r31 * rhs.x + self.r32 * rhs.y + self.r33 * rhs.z + self.r34,
+        )
+    }
+}
+
 impl fmt::Debug for CFrame {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
@@ -409,3 +1286,863 @@ impl fmt::Debug for CFrame {
         )
     }
 }
+
+/// Prints the twelve components in the order Roblox's `CFrame.new` takes
+/// them: `px, py, pz, r11, r12, r13, r21, r22, r23, r31, r32, r33`.
+impl fmt::Display for CFrame {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}",
+            self.r14,
+            self.r24,
+            self.r34,
+            self.r11,
+            self.r12,
+            self.r13,
+            self.r21,
+            self.r22,
+            self.r23,
+            self.r31,
+            self.r32,
+            self.r33
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TEST_EPSILON, project_point};
+
+    fn assert_close(a: Vec3, b: Vec3) {
+        assert!((a - b).magnitude() < TEST_EPSILON, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn tbn_is_orthonormal() {
+        let normal = Vec3::new(0.2, 0.9, 0.4);
+        let tangent = Vec3::new(1.0, 0.3, -0.2);
+        let frame = CFrame::tbn(Vec3::new(1.0, 2.0, 3.0), normal, tangent, 1.0);
+        assert!(frame.is_orthonormal(TEST_EPSILON));
+        assert_close(frame.z(), normal.unit());
+        let n = normal.unit();
+        assert_close(frame.x(), (tangent - n * tangent.dot(n)).unit());
+        assert_close(frame.p(), Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn tbn_handedness_flips_the_bitangent() {
+        let normal = Vec3::new(0.2, 0.9, 0.4);
+        let tangent = Vec3::new(1.0, 0.3, -0.2);
+        let right = CFrame::tbn(Vec3::zero(), normal, tangent, 1.0);
+        let mirrored = CFrame::tbn(Vec3::zero(), normal, tangent, -1.0);
+        assert_close(mirrored.y(), -right.y());
+        assert_close(mirrored.x(), right.x());
+        assert_close(mirrored.z(), right.z());
+        assert!(right.is_right_handed());
+        assert!(!mirrored.is_right_handed());
+    }
+
+    #[test]
+    fn difference_reports_known_offsets() {
+        let a = CFrame::from_axis_angle(Vec3::new(0.3, -0.2, 0.1), 0.4) + Vec3::new(1.0, 2.0, 3.0);
+        let b =
+            a * CFrame::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), 0.5) + Vec3::new(3.0, 0.0, -4.0);
+        let (distance, angle) = a.difference(b);
+        assert!((distance - 5.0).abs() < TEST_EPSILON);
+        assert!((angle - 0.5).abs() < TEST_EPSILON * 10.0);
+    }
+
+    #[test]
+    fn points_on_local_circle_lie_on_the_circle() {
+        let frame =
+            CFrame::from_axis_angle(Vec3::new(0.7, -0.4, 1.1), 1.3) + Vec3::new(1.0, 2.0, 3.0);
+        let points = frame.points_on_local_circle(2.5, 12);
+        assert_eq!(points.len(), 12);
+        for point in points {
+            let d = point - frame.p();
+            let local = Vec3::new(frame.x().dot(d), frame.y().dot(d), frame.z().dot(d));
+            assert!((local.magnitude() - 2.5).abs() < TEST_EPSILON * 10.0);
+            assert!(local.z.abs() < TEST_EPSILON * 10.0);
+        }
+    }
+
+    #[test]
+    fn points_on_local_sphere_lie_on_the_sphere() {
+        let frame =
+            CFrame::from_axis_angle(Vec3::new(0.7, -0.4, 1.1), 1.3) + Vec3::new(1.0, 2.0, 3.0);
+        let points = frame.points_on_local_sphere(2.5, 50);
+        assert_eq!(points.len(), 50);
+        for point in points {
+            assert!((point.distance(frame.p()) - 2.5).abs() < TEST_EPSILON * 10.0);
+        }
+    }
+
+    #[test]
+    fn transposed_array_transposed_again_is_to_array() {
+        let frame =
+            CFrame::from_axis_angle(Vec3::new(0.3, -0.2, 0.1), 0.4) + Vec3::new(1.0, 2.0, 3.0);
+        let transposed = frame.transposed_array();
+        let back: [Float; 16] = std::array::from_fn(|i| transposed[(i % 4) * 4 + i / 4]);
+        assert_eq!(back, frame.to_array());
+        assert_eq!(
+            [transposed[3], transposed[7], transposed[11]],
+            [1.0, 2.0, 3.0]
+        );
+        assert_eq!(transposed[12..16], [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn from_pos_quaternions_quarter_turn_about_z() {
+        let h = Float::sqrt(0.5);
+        let c = CFrame::from_pos_quaternions(Vec3::new(1.0, 2.0, 3.0), 0.0, 0.0, h, h);
+        assert_close(c.x(), Vec3::new(0.0, 1.0, 0.0));
+        assert_close(c.y(), Vec3::new(-1.0, 0.0, 0.0));
+        assert_close(c.z(), Vec3::new(0.0, 0.0, 1.0));
+        assert_close(c.p(), Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn best_fit_rotation_recovers_a_known_rotation() {
+        let rotation = CFrame::from_axis_angle(Vec3::new(0.5, -0.3, 1.2), 1.1);
+        let from = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.3, -0.5, 0.8),
+            Vec3::new(-2.0, 1.0, 0.5),
+        ];
+        let to = from.map(|v| (rotation * CFrame::from_pos(v)).p());
+        let fitted = best_fit_rotation(&from, &to, None);
+        assert!(fitted.approx_eq(&rotation, TEST_EPSILON * 1e3));
+        let weighted = best_fit_rotation(&from, &to, Some(&[1.0, 2.0, 0.5, 3.0]));
+        assert!(weighted.approx_eq(&rotation, TEST_EPSILON * 1e3));
+    }
+
+    #[test]
+    #[should_panic(expected = "from and to must have the same length")]
+    fn best_fit_rotation_rejects_mismatched_lengths() {
+        best_fit_rotation(&[Vec3::up(), Vec3::right()], &[Vec3::up()], None);
+    }
+
+    #[test]
+    fn split_yaw_round_trips() {
+        for frame in [
+            CFrame::from_axis_angle(Vec3::new(0.4, 1.1, -0.3), 0.9) + Vec3::new(1.0, 2.0, 3.0),
+            CFrame::from_axis_angle(Vec3::new(-1.2, -2.5, 0.8), 2.2),
+            CFrame::from_axis_angle(Vec3::right(), PI / 2.0),
+        ] {
+            let (yaw, residual) = frame.split_yaw();
+            assert_eq!(residual.p(), Vec3::zero());
+            let rebuilt = CFrame::from_axis_angle(Vec3::up(), yaw) * residual;
+            assert!(rebuilt.approx_eq(&frame.rotation(), TEST_EPSILON * 10.0));
+        }
+        let (yaw, _) = CFrame::from_axis_angle(Vec3::up(), 0.9).split_yaw();
+        assert!((yaw - 0.9).abs() < TEST_EPSILON);
+    }
+
+    #[test]
+    fn from_pos_facing_with_up_uses_primary_up() {
+        let from = Vec3::new(1.0, 2.0, 3.0);
+        let to = Vec3::new(4.0, 0.0, -1.0);
+        let frame = CFrame::from_pos_facing_with_up(from, to, Vec3::up(), Vec3::forward());
+        let z = (from - to).unit();
+        assert_close(frame.z(), z);
+        assert_close(frame.x(), Vec3::up().cross(z).unit());
+        assert_close(frame.p(), from);
+        assert!(frame.is_orthonormal(TEST_EPSILON));
+    }
+
+    #[test]
+    fn from_pos_facing_with_up_falls_back_when_parallel() {
+        let from = Vec3::new(1.0, 2.0, 3.0);
+        let frame = CFrame::from_pos_facing_with_up(
+            from,
+            from + Vec3::up() * 5.0,
+            Vec3::up(),
+            Vec3::forward(),
+        );
+        assert_close(-frame.z(), Vec3::up());
+        assert_close(frame.x(), Vec3::forward().cross(Vec3::down()));
+        assert!(frame.is_orthonormal(TEST_EPSILON));
+    }
+
+    #[test]
+    fn position_in_uses_a_rotated_reference() {
+        let reference = CFrame::from_axis_angle(Vec3::up(), PI / 2.0) + Vec3::new(1.0, 2.0, 3.0);
+        let object = CFrame::from_pos(Vec3::new(1.0, 2.0, 1.0));
+        assert_close(object.position_in(reference), Vec3::new(2.0, 0.0, 0.0));
+        let back = reference * CFrame::from_pos(object.position_in(reference));
+        assert_close(back.p(), object.p());
+    }
+
+    #[test]
+    fn rotate_by_angular_delta_matches_constant_angular_velocity() {
+        let start =
+            CFrame::from_axis_angle(Vec3::new(0.2, 0.4, -0.1), 0.5) + Vec3::new(1.0, 2.0, 3.0);
+        let omega = Vec3::new(1.0, 2.0, 3.0).unit() * 0.7;
+        let dt = 0.01;
+        let mut frame = start;
+        for _ in 0..1000 {
+            frame = frame.rotate_by_angular_delta(omega * dt);
+        }
+        let expected = start * CFrame::from_axis_angle(omega, 0.7 * 1000.0 * dt);
+        assert!(frame.approx_eq(&expected, TEST_EPSILON * 1e3));
+        assert!(frame.is_orthonormal(TEST_EPSILON));
+    }
+
+    #[test]
+    fn local_aabb_of_is_tighter_than_the_world_box() {
+        let frame =
+            CFrame::from_axis_angle(Vec3::new(0.3, 1.0, 0.2), PI / 4.0) + Vec3::new(5.0, 1.0, -2.0);
+        let half = Vec3::new(2.0, 1.0, 0.5);
+        let mut points = Vec::new();
+        for sx in [-1.0, 1.0] {
+            for sy in [-1.0, 1.0] {
+                for sz in [-1.0, 1.0] {
+                    let corner = half * Vec3::new(sx, sy, sz);
+                    points.push((frame * CFrame::from_pos(corner)).p());
+                }
+            }
+        }
+        let (min, max) = frame.local_aabb_of(&points);
+        assert!((min + half).magnitude() < TEST_EPSILON * 10.0);
+        assert!((max - half).magnitude() < TEST_EPSILON * 10.0);
+
+        let world_min = points.iter().fold(points[0], |acc, p| {
+            Vec3::new(acc.x.min(p.x), acc.y.min(p.y), acc.z.min(p.z))
+        });
+        let world_max = points.iter().fold(points[0], |acc, p| {
+            Vec3::new(acc.x.max(p.x), acc.y.max(p.y), acc.z.max(p.z))
+        });
+        let volume = |min: Vec3, max: Vec3| {
+            let size = max - min;
+            size.x * size.y * size.z
+        };
+        assert!(volume(min, max) < volume(world_min, world_max));
+    }
+
+    #[test]
+    fn local_aabb_of_empty_slice_is_zero() {
+        assert_eq!(
+            CFrame::identity().local_aabb_of(&[]),
+            (Vec3::zero(), Vec3::zero())
+        );
+    }
+
+    #[test]
+    fn orthonormalize_preserving_keeps_the_chosen_axis() {
+        let skewed = CFrame::from_columns(
+            Vec3::new(1.0, 0.2, 0.1),
+            Vec3::new(0.3, 0.9, -0.2),
+            Vec3::new(0.1, 0.4, 1.1),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        for (axis, column) in [
+            (Axis::X, CFrame::x as fn(&CFrame) -> Vec3),
+            (Axis::Y, CFrame::y),
+            (Axis::Z, CFrame::z),
+        ] {
+            let fixed = skewed.orthonormalize_preserving(axis);
+            assert!(fixed.is_orthonormal(TEST_EPSILON));
+            assert_close(column(&fixed), column(&skewed).unit());
+            assert_eq!(fixed.p(), skewed.p());
+        }
+    }
+
+    #[test]
+    fn instance_data_round_trips() {
+        let frame =
+            CFrame::from_axis_angle(Vec3::new(0.5, -1.1, 2.3), 2.0) + Vec3::new(1.0, -2.0, 3.0);
+        let data = frame.to_instance_data();
+        let (i, j, k, w) = frame.to_quaternion();
+        assert_eq!(data, [1.0, -2.0, 3.0, i, j, k, w]);
+        assert!(CFrame::from_instance_data(data).approx_eq(&frame, TEST_EPSILON));
+    }
+
+    #[test]
+    fn translation_moves_the_origin() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(cf * Vec3::zero(), Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn point_round_trips_through_world_and_object_space() {
+        let cf = CFrame::from_pos(Vec3::new(4.0, -2.0, 7.5))
+            * CFrame::from_axis_angle(Vec3::new(1.0, 2.0, -0.5).unit(), 0.83);
+        let point = Vec3::new(-3.25, 0.5, 12.0);
+        let world = cf.point_to_world_space(point);
+        assert_close(cf.point_to_object_space(world), point);
+        assert_close(
+            cf.point_to_world_space(cf.point_to_object_space(point)),
+            point,
+        );
+    }
+
+    #[test]
+    fn vector_to_world_space_of_up_is_the_y_column() {
+        for (rx, ry, rz) in [(0.0, 1.0, 0.0), (0.3, -1.2, 2.5), (PI, 0.7, -0.4)] {
+            let cf = CFrame::from_pos(Vec3::new(5.0, -6.0, 7.0))
+                * CFrame::from_axis_angle(Vec3::new(rx, ry, rz), 1.0);
+            assert_close(cf.vector_to_world_space(Vec3::up()), cf.y());
+            assert_close(cf.vector_to_object_space(cf.y()), Vec3::up());
+        }
+    }
+
+    #[test]
+    fn transform_points_and_vectors_match_single_calls() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, -2.0, 3.0)) * CFrame::angles(0.3, 1.1, -0.7);
+        let inputs = [
+            Vec3::zero(),
+            Vec3::new(4.0, 5.0, -6.0),
+            Vec3::new(-0.5, 0.25, 8.0),
+        ];
+
+        let mut points = inputs;
+        cf.transform_points(&mut points);
+        let mut vectors = inputs;
+        cf.transform_vectors(&mut vectors);
+        for ((v, point), vector) in inputs.into_iter().zip(points).zip(vectors) {
+            assert_eq!(point, cf.point_to_world_space(v));
+            assert_eq!(vector, cf.vector_to_world_space(v));
+        }
+    }
+
+    #[test]
+    fn euler_angles_xyz_round_trip_known_rotations() {
+        let (rx, ry, rz) = CFrame::angles(0.0, 0.6, 0.0).to_euler_angles_xyz();
+        assert!(
+            rx.abs() < TEST_EPSILON && (ry - 0.6).abs() < TEST_EPSILON && rz.abs() < TEST_EPSILON
+        );
+
+        for (ax, ay, az) in [(0.4, 0.0, 0.0), (0.0, 0.0, -1.1), (0.3, -0.9, 2.0)] {
+            let (rx, ry, rz) = CFrame::angles(ax, ay, az).to_euler_angles_xyz();
+            assert!((rx - ax).abs() < TEST_EPSILON, "{rx} != {ax}");
+            assert!((ry - ay).abs() < TEST_EPSILON, "{ry} != {ay}");
+            assert!((rz - az).abs() < TEST_EPSILON, "{rz} != {az}");
+        }
+    }
+
+    #[test]
+    fn euler_angles_xyz_in_gimbal_lock_still_reproduce_the_rotation() {
+        for ay in [PI / 2.0, -PI / 2.0] {
+            let cf = CFrame::angles(0.4, ay, 0.7);
+            let (rx, ry, rz) = cf.to_euler_angles_xyz();
+            assert!((ry - ay).abs() < TEST_EPSILON * 1e3, "{ry} != {ay}");
+            assert_eq!(rz, 0.0);
+            assert!(CFrame::angles(rx, ry, rz).approx_eq(&cf, TEST_EPSILON * 1e3));
+        }
+    }
+
+    #[test]
+    fn angles_quarter_turn_about_y_turns_z_onto_x() {
+        let cf = CFrame::angles(0.0, PI / 2.0, 0.0);
+        assert_close(cf.z(), Vec3::new(1.0, 0.0, 0.0));
+        assert_close(cf.y(), Vec3::up());
+        assert_eq!(cf.p(), Vec3::zero());
+    }
+
+    #[test]
+    fn to_quaternion_of_a_third_turn_about_the_diagonal() {
+        let cf = CFrame::from_axis_angle(Vec3::new(1.0, 1.0, 1.0).unit(), 2.0 * PI / 3.0);
+        let (i, j, k, w) = cf.to_quaternion();
+        let sign = w.signum();
+        for c in [i, j, k, w] {
+            assert!((c * sign - 0.5).abs() < TEST_EPSILON, "{c} != 0.5");
+        }
+        let back = CFrame::from_pos_quaternions(Vec3::zero(), i, j, k, w);
+        assert!(back.approx_eq(&cf, TEST_EPSILON));
+    }
+
+    #[test]
+    fn slerp_halfway_to_a_quarter_yaw_is_an_eighth_yaw() {
+        let start = CFrame::identity();
+        let end = CFrame::from_axis_angle(Vec3::up(), PI / 2.0);
+        let half = start.slerp(&end, 0.5);
+        assert!(half.approx_eq(&CFrame::from_axis_angle(Vec3::up(), PI / 4.0), TEST_EPSILON));
+        assert_eq!(start.slerp(&end, 0.0), start);
+        assert_eq!(start.slerp(&end, 1.0), end);
+    }
+
+    #[test]
+    fn lerp_halfway_to_a_translated_frame_is_the_midpoint() {
+        let end = CFrame::from_pos(Vec3::new(2.0, -4.0, 6.0));
+        let half = CFrame::identity().lerp(&end, 0.5);
+        assert!(half.approx_eq(&CFrame::from_pos(Vec3::new(1.0, -2.0, 3.0)), TEST_EPSILON));
+    }
+
+    #[test]
+    fn orthonormalize_repairs_a_skewed_basis() {
+        let skewed = CFrame::from_columns(
+            Vec3::new(1.0, 0.2, 0.0),
+            Vec3::new(0.3, 1.1, -0.1),
+            Vec3::new(0.05, 0.0, 0.9),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        let fixed = skewed.orthonormalize();
+        assert!((fixed.determinant() - 1.0).abs() < TEST_EPSILON);
+        assert!(fixed.x().dot(fixed.y()).abs() < TEST_EPSILON);
+        assert!(fixed.y().dot(fixed.z()).abs() < TEST_EPSILON);
+        assert!(fixed.z().dot(fixed.x()).abs() < TEST_EPSILON);
+        assert_eq!(fixed.p(), skewed.p());
+    }
+
+    #[test]
+    fn components_round_trip_through_from_components() {
+        let cf = CFrame::from_pos(Vec3::new(1.5, -2.0, 3.25))
+            * CFrame::from_axis_angle(Vec3::new(0.2, 0.4, -0.6), 0.7);
+        let (m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34) = cf.components();
+        let back =
+            CFrame::from_components(m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34);
+        assert_eq!(back, cf);
+        assert_eq!(Vec3::new(m14, m24, m34), cf.p());
+    }
+
+    #[test]
+    fn rotation_has_zero_position() {
+        let cf = CFrame::from_pos(Vec3::new(4.0, 5.0, 6.0))
+            * CFrame::from_axis_angle(Vec3::new(0.3, -0.8, 1.2), 1.1);
+        let rotation = cf.rotation();
+        assert_eq!(rotation.p(), Vec3::zero());
+        assert_close(rotation * Vec3::up(), cf.vector_to_world_space(Vec3::up()));
+    }
+
+    #[test]
+    fn identity_looks_forward() {
+        let cf = CFrame::identity();
+        assert_eq!(cf.look_vector(), Vec3::forward());
+        assert_eq!(cf.right(), Vec3::right());
+        assert_eq!(cf.up_vector(), Vec3::up());
+    }
+
+    #[test]
+    fn identity_displays_position_first() {
+        assert_eq!(
+            CFrame::identity().to_string(),
+            "0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1"
+        );
+        assert_eq!(
+            CFrame::from_pos(Vec3::new(1.5, -2.0, 3.0)).to_string(),
+            "1.5, -2, 3, 1, 0, 0, 0, 1, 0, 0, 0, 1"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trips_named_components() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, 2.0, 3.0)) * CFrame::angles(0.1, 0.2, 0.3);
+        let json = serde_json::to_string(&cf).unwrap();
+        assert!(json.contains("\"r14\":1.0"), "{json}");
+        let back: CFrame = serde_json::from_str(&json).unwrap();
+        assert!(back.approx_eq(&cf, TEST_EPSILON));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_accepts_a_non_orthonormal_frame() {
+        let json = r#"{"r11":2.0,"r12":0.5,"r13":0.0,"r14":1.0,
+            "r21":0.0,"r22":3.0,"r23":0.0,"r24":2.0,
+            "r31":0.0,"r32":0.0,"r33":4.0,"r34":3.0}"#;
+        let cf: CFrame = serde_json::from_str(json).unwrap();
+        assert_eq!(cf.x(), Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(cf.p(), Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn approx_eq_tolerates_what_eq_does_not() {
+        let a = CFrame::identity();
+        let b = CFrame::from_pos(Vec3::new(1e-12, 0.0, -1e-12));
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&CFrame::from_pos(Vec3::new(1e-6, 0.0, 0.0)), 1e-9));
+    }
+
+    #[test]
+    fn orthographic_maps_the_box_centre_to_the_ndc_origin() {
+        let ortho = CFrame::orthographic(-2.0, 6.0, -1.0, 3.0, 0.5, 10.5);
+        let centre = Vec3::new(2.0, 1.0, -5.5);
+        assert_close(project_point(&ortho, centre), Vec3::zero());
+        assert_close(
+            project_point(&ortho, Vec3::new(6.0, 3.0, -0.5)),
+            Vec3::new(1.0, 1.0, -1.0),
+        );
+    }
+
+    #[test]
+    fn inverse_composes_to_identity_on_both_sides() {
+        let rigid = CFrame::from_pos(Vec3::new(3.0, -1.0, 2.0))
+            * CFrame::from_axis_angle(Vec3::new(0.2, 1.0, -0.4).unit(), 1.3);
+        let scaled =
+            CFrame::from_columns(rigid.x() * 2.0, rigid.y() * 0.5, rigid.z() * 3.0, rigid.p());
+        for cf in [rigid, scaled] {
+            assert!((cf * cf.inverse()).approx_eq(&CFrame::identity(), TEST_EPSILON * 10.0));
+            assert!((cf.inverse() * cf).approx_eq(&CFrame::identity(), TEST_EPSILON * 10.0));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_cast)]
+    fn to_array_f32_matches_casting_to_array() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, -2.5, 3.0)) * CFrame::angles(0.4, 1.1, -0.2);
+        let array = cf.to_array();
+        let array_f32 = cf.to_array_f32();
+        for (i, (a, b)) in array.iter().zip(array_f32).enumerate() {
+            assert_eq!(*a as f32, b, "element {i}");
+        }
+    }
+
+    #[test]
+    fn from_matrix_reads_back_to_array() {
+        let cf = CFrame::from_pos(Vec3::new(-1.0, 4.0, 2.5)) * CFrame::angles(1.2, 0.3, -0.9);
+        assert!(CFrame::from_matrix(cf.to_array()).approx_eq(&cf, TEST_EPSILON));
+    }
+
+    #[test]
+    fn translation_indices_differ_between_layouts() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, 2.0, 3.0)) * CFrame::angles(0.5, 0.0, 0.0);
+        let column_major = cf.to_array();
+        let row_major = cf.to_array_row_major();
+        assert_eq!(
+            [column_major[12], column_major[13], column_major[14]],
+            [1.0, 2.0, 3.0]
+        );
+        assert_eq!([row_major[3], row_major[7], row_major[11]], [1.0, 2.0, 3.0]);
+        assert_eq!(
+            [row_major[12], row_major[13], row_major[14]],
+            [0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn axis_angle_round_trips() {
+        let pairs = [
+            (Vec3::up(), 0.7),
+            (Vec3::new(1.0, -2.0, 0.5).unit(), 2.1),
+            (Vec3::new(0.0, 1.0, 1.0).unit(), PI),
+        ];
+        for (axis, angle) in pairs {
+            let cf = CFrame::from_axis_angle(axis, angle);
+            let (back_axis, back_angle) = cf.to_axis_angle();
+            assert!(
+                (back_angle - angle).abs() < TEST_EPSILON * 1e3,
+                "{back_angle} != {angle}"
+            );
+            // At a half turn the axis may come back negated, which is the same rotation.
+            let rebuilt = CFrame::from_axis_angle(back_axis, back_angle);
+            assert!(rebuilt.approx_eq(&cf, TEST_EPSILON * 1e3));
+        }
+
+        let (axis, angle) = CFrame::identity().to_axis_angle();
+        assert_eq!(angle, 0.0);
+        assert!((axis.magnitude() - 1.0).abs() < TEST_EPSILON);
+    }
+
+    #[test]
+    fn from_rotation_between_parallel_and_antiparallel() {
+        let v = Vec3::new(1.0, 2.0, -0.5);
+        assert!(
+            CFrame::from_rotation_between(v, v * 2.0).approx_eq(&CFrame::identity(), TEST_EPSILON)
+        );
+
+        for (from, to) in [(v, -v), (Vec3::up(), Vec3::down())] {
+            let cf = CFrame::from_rotation_between(from, to);
+            assert_close(cf.vector_to_world_space(from.unit()), to.unit());
+            assert!(cf.is_orthonormal(TEST_EPSILON));
+        }
+
+        let cf = CFrame::from_rotation_between(Vec3::right(), Vec3::up());
+        assert_close(cf.vector_to_world_space(Vec3::right()), Vec3::up());
+    }
+
+    #[test]
+    fn with_position_keeps_the_rotation_columns() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, 1.0, 1.0)) * CFrame::angles(0.2, -0.4, 0.9);
+        let moved = cf.with_position(Vec3::new(-5.0, 6.0, 7.0));
+        assert_eq!(moved.x(), cf.x());
+        assert_eq!(moved.y(), cf.y());
+        assert_eq!(moved.z(), cf.z());
+        assert_eq!(moved.p(), Vec3::new(-5.0, 6.0, 7.0));
+    }
+
+    #[test]
+    fn scaled_by_two_doubles_each_column() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, 2.0, 3.0)) * CFrame::angles(0.3, 0.6, -1.0);
+        let scaled = cf.scaled(Vec3::new(2.0, 2.0, 2.0));
+        assert_close(scaled.x(), cf.x() * 2.0);
+        assert_close(scaled.y(), cf.y() * 2.0);
+        assert_close(scaled.z(), cf.z() * 2.0);
+        assert!((scaled.x().magnitude() - 2.0).abs() < TEST_EPSILON);
+        assert_eq!(scaled.p(), cf.p());
+    }
+
+    #[test]
+    fn decompose_recovers_translation_rotation_and_scale() {
+        let rotation = CFrame::angles(0.4, -1.1, 0.25);
+        let scale = Vec3::new(1.5, 0.5, 3.0);
+        let position = Vec3::new(-2.0, 7.0, 1.0);
+        let cf = (CFrame::from_pos(position) * rotation).scaled(scale);
+
+        assert_close(cf.extract_scale(), scale);
+        let (p, r, s) = cf.decompose();
+        assert_eq!(p, position);
+        assert!(r.approx_eq(&rotation, TEST_EPSILON * 10.0));
+        assert_close(s, scale);
+    }
+
+    #[test]
+    fn near_and_far_planes_map_to_ndc_depth_in_both_handednesses() {
+        let (near, far) = (0.5, 100.0);
+        let rh = CFrame::perspective(1.0, 1.5, near, far);
+        let lh = CFrame::perspective_lh(1.0, 1.5, near, far);
+        let depth = |m: &[Float; 16], z: Float| project_point(m, Vec3::new(0.0, 0.0, z)).z;
+        assert!((depth(&rh, -near) + 1.0).abs() < TEST_EPSILON);
+        assert!((depth(&lh, near) + 1.0).abs() < TEST_EPSILON);
+        assert!((depth(&rh, -far) - 1.0).abs() < TEST_EPSILON * 1e3);
+        assert!((depth(&lh, far) - 1.0).abs() < TEST_EPSILON * 1e3);
+    }
+
+    #[test]
+    fn perspective_zo_maps_depth_to_zero_one() {
+        let (near, far) = (0.5, 100.0);
+        let zo = CFrame::perspective_zo(1.0, 1.5, near, far);
+        let depth = |z: Float| project_point(&zo, Vec3::new(0.0, 0.0, z)).z;
+        assert!(depth(-near).abs() < TEST_EPSILON);
+        assert!((depth(-far) - 1.0).abs() < TEST_EPSILON * 1e3);
+    }
+
+    #[test]
+    fn perspective_reverse_z_maps_near_to_one_and_far_to_zero() {
+        let near = 0.5;
+        let finite = CFrame::perspective_reverse_z(1.0, 1.5, near, 100.0);
+        let depth = |m: &[Float; 16], z: Float| project_point(m, Vec3::new(0.0, 0.0, z)).z;
+        assert!((depth(&finite, -near) - 1.0).abs() < TEST_EPSILON);
+        assert!(depth(&finite, -100.0).abs() < TEST_EPSILON * 1e3);
+
+        let infinite = CFrame::perspective_reverse_z(1.0, 1.5, near, Float::INFINITY);
+        assert!(infinite.iter().all(|c| c.is_finite()));
+        assert!((depth(&infinite, -near) - 1.0).abs() < TEST_EPSILON);
+        let distant = depth(&infinite, -1e6);
+        assert!(distant > 0.0 && distant < 1e-6, "{distant}");
+    }
+
+    #[test]
+    fn look_at_model_sits_at_the_eye() {
+        let eye = Vec3::new(1.0, 2.0, 3.0);
+        let center = Vec3::new(-4.0, 0.0, 1.0);
+        let cf = CFrame::look_at_model(eye, center, Vec3::up());
+        assert_eq!(cf.p(), eye);
+        assert_close(cf.look_vector(), (center - eye).unit());
+
+        let straight_up = CFrame::look_at_model(eye, eye + Vec3::up(), Vec3::up());
+        assert_eq!(straight_up.p(), eye);
+        assert!(straight_up.is_orthonormal(TEST_EPSILON));
+    }
+
+    #[test]
+    fn look_at_moves_the_eye_to_the_origin() {
+        let eye = Vec3::new(3.0, -1.0, 4.0);
+        let center = Vec3::new(-2.0, 5.0, 0.5);
+        let view = CFrame::look_at(eye, center);
+        assert_close(view * eye, Vec3::zero());
+        assert_close(
+            view * center,
+            Vec3::new(0.0, 0.0, -(center - eye).magnitude()),
+        );
+    }
+
+    #[test]
+    fn look_at_up_looking_straight_down() {
+        let eye = Vec3::new(0.0, 10.0, 0.0);
+        let view = CFrame::look_at_up(eye, Vec3::zero(), Vec3::forward());
+        assert!(view.is_orthonormal(TEST_EPSILON));
+        assert_close(view * Vec3::zero(), Vec3::new(0.0, 0.0, -10.0));
+        // The up hint becomes the top of the screen.
+        assert_close(view.vector_to_world_space(Vec3::forward()), Vec3::up());
+
+        // The default up is parallel here, so look_at has to fall back.
+        let fallback = CFrame::look_at(eye, Vec3::zero());
+        assert!(fallback.is_orthonormal(TEST_EPSILON));
+        assert_close(fallback * Vec3::zero(), Vec3::new(0.0, 0.0, -10.0));
+    }
+
+    #[test]
+    fn constructors_work_in_const_and_static_contexts() {
+        const ORIGIN: Vec3 = Vec3::zero();
+        const FRAME: CFrame = CFrame::IDENTITY;
+        const MOVED: CFrame =
+            CFrame::from_components(1.0, 0.0, 0.0, 5.0, 0.0, 1.0, 0.0, 6.0, 0.0, 0.0, 1.0, 7.0);
+        static BASIS: [Vec3; 3] = [Vec3::right(), Vec3::up(), Vec3::backward()];
+
+        assert_eq!(ORIGIN, Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(FRAME, CFrame::identity());
+        assert_eq!(MOVED, CFrame::from_pos(Vec3::new(5.0, 6.0, 7.0)));
+        assert_eq!([FRAME.x(), FRAME.y(), FRAME.z()], BASIS);
+    }
+
+    #[test]
+    fn is_orthonormal_rejects_a_scaled_frame() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, 2.0, 3.0)) * CFrame::angles(0.7, -0.2, 1.9);
+        assert!(cf.is_orthonormal(TEST_EPSILON));
+        assert!(
+            !cf.scaled(Vec3::new(1.0, 2.0, 1.0))
+                .is_orthonormal(TEST_EPSILON)
+        );
+    }
+
+    #[test]
+    fn orthonormal_inverse_matches_inverse() {
+        let cf = CFrame::from_pos(Vec3::new(-3.0, 8.0, 0.5)) * CFrame::angles(1.4, 0.3, -2.2);
+        assert!(
+            cf.orthonormal_inverse()
+                .approx_eq(&cf.inverse(), TEST_EPSILON)
+        );
+    }
+
+    #[test]
+    fn move_toward_clamps_at_the_target() {
+        let cf = CFrame::angles(0.3, 0.2, 0.1);
+        let target = Vec3::new(3.0, 4.0, 0.0);
+        let stepped = cf.move_toward(target, 2.0);
+        assert_close(stepped.p(), Vec3::new(1.2, 1.6, 0.0));
+        assert_eq!(cf.move_toward(target, 10.0).p(), target);
+        assert_eq!(cf.move_toward(target, 10.0).rotation(), cf);
+    }
+
+    #[test]
+    fn move_toward_with_a_negative_step_stays_on_the_target() {
+        let cf = CFrame::from_pos(Vec3::new(3.0, 4.0, 0.0)) * CFrame::angles(0.3, 0.2, 0.1);
+        assert_eq!(cf.move_toward(cf.p(), -1.0), cf);
+    }
+
+    #[test]
+    fn transpose_rotation_twice_is_the_original() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, 2.0, 3.0)) * CFrame::angles(0.5, -0.3, 1.7);
+        let transposed = cf.transpose_rotation();
+        assert_eq!(transposed.p(), cf.p());
+        assert_ne!(transposed, cf);
+        assert_eq!(transposed.transpose_rotation(), cf);
+    }
+
+    #[test]
+    fn blend_can_snap_position_and_keep_rotation() {
+        let start = CFrame::angles(0.2, 0.9, -0.4);
+        let end = CFrame::from_pos(Vec3::new(4.0, -1.0, 2.0)) * CFrame::angles(-1.0, 0.1, 0.6);
+        let blended = start.blend(&end, 1.0, 0.0);
+        assert_close(blended.p(), end.p());
+        assert!(blended.rotation().approx_eq(&start, TEST_EPSILON));
+    }
+
+    #[test]
+    fn composing_scaled_frames_matches_applying_them_in_turn() {
+        let parent = (CFrame::from_pos(Vec3::new(1.0, 2.0, 3.0)) * CFrame::angles(0.3, 0.5, -0.2))
+            .scaled(Vec3::new(2.0, 0.5, 1.5));
+        let child = (CFrame::from_pos(Vec3::new(-1.0, 0.5, 4.0)) * CFrame::angles(-0.7, 0.1, 1.0))
+            .scaled(Vec3::new(0.25, 3.0, 1.0));
+        let point = Vec3::new(0.5, -1.5, 2.0);
+        assert!((parent * child * point).approx_eq(parent * (child * point), TEST_EPSILON * 10.0));
+    }
+
+    #[test]
+    fn relative_to_and_to_world_space_round_trip() {
+        let parent = CFrame::from_pos(Vec3::new(3.0, 0.0, -2.0)) * CFrame::angles(0.4, 1.0, 0.0);
+        let child = CFrame::from_pos(Vec3::new(-1.0, 5.0, 2.0)) * CFrame::angles(0.0, -0.3, 2.0);
+        let local = child.relative_to(&parent);
+        assert!(
+            local
+                .to_world_space(&parent)
+                .approx_eq(&child, TEST_EPSILON * 10.0)
+        );
+        assert!((parent * local).approx_eq(&child, TEST_EPSILON * 10.0));
+    }
+
+    #[test]
+    fn nearly_vertical_look_directions_stay_orthonormal() {
+        let from = Vec3::new(1.0, 2.0, 3.0);
+        let to = from + Vec3::new(1e-9, 1.0, 0.0);
+        let facing = CFrame::from_pos_facing(from, to);
+        assert!(facing.is_orthonormal(TEST_EPSILON));
+        assert_close(facing.look_vector(), Vec3::up());
+        assert!(CFrame::look_at(from, to).is_orthonormal(TEST_EPSILON));
+        assert!(CFrame::look_at(to, from).is_orthonormal(TEST_EPSILON));
+    }
+
+    #[test]
+    fn from_pos_facing_builds_a_unit_perpendicular_basis() {
+        let cf = CFrame::from_pos_facing(Vec3::new(1.0, -2.0, 0.5), Vec3::new(-3.0, 4.0, 2.0));
+        assert!(cf.is_orthonormal(TEST_EPSILON));
+    }
+
+    #[test]
+    fn mirrored_frame_is_not_right_handed() {
+        let cf = CFrame::angles(0.3, 0.2, 0.1);
+        assert!(cf.is_right_handed());
+        let mirrored = CFrame::from_columns(cf.y(), cf.x(), cf.z(), cf.p());
+        assert!(!mirrored.is_right_handed());
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference_multiplication_matches_owned() {
+        let a = CFrame::from_pos(Vec3::new(1.0, 2.0, 3.0)) * CFrame::angles(0.1, 0.2, 0.3);
+        let b = CFrame::from_pos(Vec3::new(-4.0, 0.0, 1.0)) * CFrame::angles(1.0, -0.5, 0.0);
+        assert_eq!(&a * &b, a * b);
+        assert_eq!(&a * b, a * b);
+        assert_eq!(a * &b, a * b);
+    }
+
+    #[test]
+    fn subtracting_frames_gives_the_position_offset() {
+        let a = CFrame::from_pos(Vec3::new(5.0, 2.0, -1.0)) * CFrame::angles(0.4, 0.0, 0.0);
+        let b = CFrame::from_pos(Vec3::new(1.0, 4.0, 3.0));
+        assert_eq!(a - b, Vec3::new(4.0, -2.0, -4.0));
+    }
+
+    #[test]
+    fn angular_and_position_distance() {
+        let a = CFrame::from_pos(Vec3::new(1.0, 2.0, 3.0)) * CFrame::angles(0.3, -0.6, 0.9);
+        let moved = a.with_position(Vec3::new(4.0, 6.0, 3.0));
+        assert!(a.angular_distance(&moved).abs() < TEST_EPSILON * 1e3);
+        assert_eq!(a.position_distance(&moved), 5.0);
+
+        let turned = a * CFrame::from_axis_angle(Vec3::up(), PI / 2.0);
+        assert!((a.angular_distance(&turned) - PI / 2.0).abs() < TEST_EPSILON * 1e3);
+    }
+
+    #[test]
+    fn try_from_columns_accepts_and_rejects() {
+        let cf = CFrame::angles(0.2, -0.4, 0.8);
+        let p = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(
+            CFrame::try_from_columns(cf.x(), cf.y(), cf.z(), p),
+            Ok(CFrame::from_columns(cf.x(), cf.y(), cf.z(), p))
+        );
+
+        let skewed = (cf.x() + cf.y() * 0.1).unit();
+        assert_eq!(
+            CFrame::try_from_columns(skewed, cf.y(), cf.z(), p),
+            Err("basis columns are not perpendicular")
+        );
+        assert_eq!(
+            CFrame::try_from_columns(cf.x() * 2.0, cf.y(), cf.z(), p),
+            Err("basis column is not unit length")
+        );
+        assert_eq!(
+            CFrame::try_from_columns(cf.x(), cf.y(), -cf.z(), p),
+            Err("basis is left-handed")
+        );
+    }
+
+    #[test]
+    fn from_pos_look_up_sets_the_look_vector_and_position() {
+        let pos = Vec3::new(2.0, -1.0, 4.0);
+        let look = Vec3::new(1.0, 0.5, -2.0);
+        let cf = CFrame::from_pos_look_up(pos, look, Vec3::up());
+        assert_eq!(cf.p(), pos);
+        assert_close(cf.look_vector(), look.unit());
+        assert!(cf.up_vector().y > 0.0);
+
+        let straight_up = CFrame::from_pos_look_up(pos, Vec3::up(), Vec3::up());
+        assert!(straight_up.is_orthonormal(TEST_EPSILON));
+        assert_close(straight_up.look_vector(), Vec3::up());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::Float;
+
+/// A unit dual quaternion representing a rigid transform, stored as `real`
+/// (the rotation) and `dual` (half the translation times the rotation), both
+/// in `[i, j, k, w]` order to match `CFrame::to_quaternion`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DualQuat {
+    pub real: [Float; 4],
+    pub dual: [Float; 4],
+}
+
+impl DualQuat {
+    pub fn new(real: [Float; 4], dual: [Float; 4]) -> Self {
+        Self { real, dual }
+    }
+
+    pub fn identity() -> Self {
+        Self {
+            real: [0.0, 0.0, 0.0, 1.0],
+            dual: [0.0, 0.0, 0.0, 0.0],
+        }
+    }
+
+    /// Scales both parts so the real part has unit length.
+    pub fn normalize(self) -> Self {
+        let [i, j, k, w] = self.real;
+        let mag = (i * i + j * j + k * k + w * w).sqrt();
+        if mag == 0.0 {
+            return Self::identity();
+        }
+        let inv_mag = 1.0 / mag;
+        Self {
+            real: self.real.map(|c| c * inv_mag),
+            dual: self.dual.map(|c| c * inv_mag),
+        }
+    }
+
+    /// Dual quaternion linear blending: the weighted sum of the transforms,
+    /// normalized. Each quaternion is flipped into the same hemisphere as the
+    /// first so the blend takes the short way around.
+    pub fn blend(transforms: &[(DualQuat, Float)]) -> Self {
+        let Some((pivot, _)) = transforms.first() else {
+            return Self::identity();
+        };
+        let mut real = [0.0; 4];
+        let mut dual = [0.0; 4];
+        for (dq, weight) in transforms {
+            let weight = if quat_dot(dq.real, pivot.real) < 0.0 {
+                -weight
+            } else {
+                *weight
+            };
+            for index in 0..4 {
+                real[index] += dq.real[index] * weight;
+                dual[index] += dq.dual[index] * weight;
+            }
+        }
+        Self { real, dual }.normalize()
+    }
+}
+
+pub(crate) fn quat_mul(a: [Float; 4], b: [Float; 4]) -> [Float; 4] {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+fn quat_dot(a: [Float; 4], b: [Float; 4]) -> Float {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CFrame, TEST_EPSILON, Vec3, consts::FRAC_PI_3};
+
+    #[test]
+    fn cframe_round_trips_through_dual_quat() {
+        let frame =
+            CFrame::from_axis_angle(Vec3::new(0.5, -1.1, 0.3), 1.2) + Vec3::new(1.0, -2.0, 3.0);
+        let back = CFrame::from_dual_quaternion(frame.to_dual_quaternion());
+        assert!(back.approx_eq(&frame, TEST_EPSILON * 10.0));
+    }
+
+    #[test]
+    fn blend_keeps_distance_to_a_bent_joint() {
+        // Two bones sharing a joint at `pivot`; the second is bent 120 degrees.
+        let pivot = Vec3::new(1.0, 0.0, 0.0);
+        let bend = CFrame::from_pos(pivot)
+            * CFrame::from_axis_angle(Vec3::backward(), 2.0 * FRAC_PI_3)
+            * CFrame::from_pos(-pivot);
+        let point = pivot + Vec3::up();
+
+        let blended = CFrame::from_dual_quaternion(DualQuat::blend(&[
+            (CFrame::identity().to_dual_quaternion(), 0.5),
+            (bend.to_dual_quaternion(), 0.5),
+        ]));
+        let halfway = CFrame::from_pos(pivot)
+            * CFrame::from_axis_angle(Vec3::backward(), FRAC_PI_3)
+            * CFrame::from_pos(-pivot);
+        let moved = (blended * CFrame::from_pos(point)).p();
+        assert!(moved.distance((halfway * CFrame::from_pos(point)).p()) < TEST_EPSILON * 10.0);
+
+        // Averaging the matrices instead collapses the point toward the joint.
+        let matrix_blend = (point + (bend * CFrame::from_pos(point)).p()) * 0.5;
+        assert!((moved.distance(pivot) - 1.0).abs() < TEST_EPSILON * 10.0);
+        assert!((matrix_blend.distance(pivot) - 0.5).abs() < TEST_EPSILON * 10.0);
+    }
+}
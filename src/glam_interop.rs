@@ -0,0 +1,64 @@
+use crate::{CFrame, Float, Vec3};
+
+impl From<glam::Vec3> for Vec3 {
+    fn from(v: glam::Vec3) -> Self {
+        Vec3::new(Float::from(v.x), Float::from(v.y), Float::from(v.z))
+    }
+}
+
+impl From<Vec3> for glam::Vec3 {
+    #[allow(clippy::unnecessary_cast)]
+    fn from(v: Vec3) -> Self {
+        glam::Vec3::new(v.x as f32, v.y as f32, v.z as f32)
+    }
+}
+
+impl From<CFrame> for glam::Mat4 {
+    fn from(cf: CFrame) -> Self {
+        glam::Mat4::from_cols_array(&cf.to_array_f32())
+    }
+}
+
+/// Fails if the bottom row isn't `[0, 0, 0, 1]`, since a `CFrame` can only
+/// hold affine transforms.
+impl TryFrom<glam::Mat4> for CFrame {
+    type Error = &'static str;
+
+    fn try_from(m: glam::Mat4) -> Result<Self, Self::Error> {
+        if m.row(3) != glam::Vec4::W {
+            return Err("matrix is not affine: bottom row must be [0, 0, 0, 1]");
+        }
+        Ok(CFrame::from_matrix(m.to_cols_array().map(Float::from)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec3_round_trips_through_glam() {
+        let v = Vec3::new(1.5, -2.0, 0.25);
+        let g = glam::Vec3::from(v);
+        assert_eq!(g, glam::Vec3::new(1.5, -2.0, 0.25));
+        assert_eq!(Vec3::from(g), v);
+    }
+
+    #[test]
+    fn cframe_round_trips_through_mat4() {
+        let cf = CFrame::from_pos(Vec3::new(1.0, -2.0, 3.5)) * CFrame::angles(0.3, -0.7, 1.1);
+        let m = glam::Mat4::from(cf);
+        assert_eq!(m.to_cols_array(), cf.to_array_f32());
+        let back = CFrame::try_from(m).unwrap();
+        // The trip goes through f32, so only f32 precision survives.
+        assert!(back.approx_eq(&cf, 1e-6));
+    }
+
+    #[test]
+    fn non_affine_mat4_is_rejected() {
+        let mut cols = CFrame::identity().to_array_f32();
+        cols[3] = 0.5;
+        let m = glam::Mat4::from_cols_array(&cols);
+        assert!(CFrame::try_from(m).is_err());
+    }
+}
@@ -1,7 +1,60 @@
-type Float = f64;
+/// The scalar type used throughout the crate: `f64`, or `f32` with the
+/// `f32` feature.
+#[cfg(not(feature = "f32"))]
+pub type Float = f64;
+#[cfg(feature = "f32")]
+pub type Float = f32;
 
+#[cfg(feature = "f32")]
+use std::f32::consts;
+#[cfg(not(feature = "f32"))]
+use std::f64::consts;
+
+mod cached_cframe;
 mod cframe;
+mod dual_quat;
+#[cfg(feature = "glam")]
+mod glam_interop;
+mod spring;
+mod vec2;
 mod vec3;
+mod vec4;
+
+pub use cached_cframe::CachedCFrame;
+pub use cframe::{Axis, CFrame, best_fit_rotation};
+pub use dual_quat::DualQuat;
+pub use spring::CFrameSpring;
+pub use vec2::Vec2;
+#[cfg(feature = "hash")]
+pub use vec3::Vec3Bits;
+pub use vec3::{Vec3, batch_cross, batch_dot};
+pub use vec4::{Vec4, mat4_mul_vec4, project_point};
+
+/// Tolerance for float comparisons in tests, loose enough for the active
+/// precision.
+#[cfg(all(test, not(feature = "f32")))]
+const TEST_EPSILON: Float = 1e-12;
+#[cfg(all(test, feature = "f32"))]
+const TEST_EPSILON: Float = 1e-5;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Run once with default features and once with `--features f32`.
+    #[test]
+    fn float_matches_active_precision() {
+        let expected = if cfg!(feature = "f32") { 4 } else { 8 };
+        assert_eq!(std::mem::size_of::<Float>(), expected);
+    }
 
-pub use cframe::CFrame;
-pub use vec3::Vec3;
+    #[test]
+    fn known_transform_under_active_precision() {
+        let cframe =
+            CFrame::from_axis_angle(Vec3::up(), consts::FRAC_PI_2) + Vec3::new(1.0, 2.0, 3.0);
+        let moved = cframe * Vec3::new(1.0, 0.0, 0.0);
+        assert!(moved.approx_eq(Vec3::new(1.0, 2.0, 2.0), TEST_EPSILON));
+        let back = cframe.inverse() * moved;
+        assert!(back.approx_eq(Vec3::new(1.0, 0.0, 0.0), TEST_EPSILON));
+    }
+}
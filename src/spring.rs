@@ -0,0 +1,99 @@
+use crate::{CFrame, Float, Vec3};
+
+/// Spring state for making a frame trail a moving target.
+///
+/// Position is sprung linearly, and rotation is sprung in the tangent space
+/// of the current orientation: the rotation error is taken through the log
+/// map to a rotation vector and the angular velocity is integrated back
+/// through the exponential map. A damping of `2.0 * stiffness.sqrt()` is
+/// critically damped.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CFrameSpring {
+    pub velocity: Vec3,
+    pub angular_velocity: Vec3,
+}
+
+impl CFrameSpring {
+    pub fn new() -> Self {
+        Self {
+            velocity: Vec3::zero(),
+            angular_velocity: Vec3::zero(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        current: CFrame,
+        target: CFrame,
+        stiffness: Float,
+        damping: Float,
+        dt: Float,
+    ) -> CFrame {
+        let pos = current.p();
+        let accel = (target.p() - pos) * stiffness - self.velocity * damping;
+        self.velocity += accel * dt;
+        let pos = pos + self.velocity * dt;
+
+        let current_rot = current.rotation();
+        let target_rot = target.rotation();
+        let error = (target_rot * current_rot.transpose_rotation()).rotation_vector();
+        let angular_accel = error * stiffness - self.angular_velocity * damping;
+        self.angular_velocity += angular_accel * dt;
+        let step = self.angular_velocity * dt;
+        CFrame::from_axis_angle(step, step.magnitude()) * current_rot + pos
+    }
+}
+
+impl Default for CFrameSpring {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_EPSILON;
+
+    #[test]
+    fn update_settles_on_the_target() {
+        let target =
+            CFrame::from_axis_angle(Vec3::new(0.4, -1.2, 0.3), 1.3) + Vec3::new(4.0, -2.0, 7.0);
+        let mut current = CFrame::identity();
+        let mut spring = CFrameSpring::new();
+        for _ in 0..600 {
+            current = spring.update(current, target, 100.0, 20.0, 1.0 / 60.0);
+        }
+        assert!(current.p().distance(target.p()) < 1e-4);
+        assert!(current.angle_to(target) < 1e-4);
+        assert!(current.is_orthonormal(TEST_EPSILON * 1e3));
+    }
+
+    #[test]
+    fn critically_damped_update_does_not_overshoot() {
+        let target = CFrame::from_axis_angle(Vec3::up(), 1.5) + Vec3::new(10.0, 0.0, 0.0);
+        let mut current = CFrame::identity();
+        let mut spring = CFrameSpring::new();
+        let (stiffness, damping) = (100.0, 2.0 * Float::sqrt(100.0));
+        // The rotation error the spring itself sees, which stays accurate
+        // near zero where `angle_to`'s acos does not.
+        let error = |c: CFrame| {
+            (target * c.transpose_rotation())
+                .rotation_vector()
+                .magnitude()
+        };
+        let mut last_x = current.p().x;
+        let mut last_angle = error(current);
+        for _ in 0..600 {
+            current = spring.update(current, target, stiffness, damping, 1.0 / 60.0);
+            let x = current.p().x;
+            let angle = error(current);
+            assert!(x >= last_x && x <= 10.0, "position overshot: {x}");
+            assert!(
+                angle <= last_angle + TEST_EPSILON,
+                "rotation overshot: {angle}"
+            );
+            (last_x, last_angle) = (x, angle);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use core::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Float, Vec3};
+
+/// A 2D vector for screen-space and UI work, with the same basic operators
+/// as `Vec3`.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Vec2 {
+    pub x: Float,
+    pub y: Float,
+}
+
+impl Vec2 {
+    pub const fn new(x: Float, y: Float) -> Self {
+        Self { x, y }
+    }
+
+    pub const fn zero() -> Self {
+        Self { x: 0.0, y: 0.0 }
+    }
+
+    /// Adds a z component to make a `Vec3`.
+    pub const fn extend(self, z: Float) -> Vec3 {
+        Vec3::new(self.x, self.y, z)
+    }
+
+    pub fn lerp(self, other: Self, t: Float) -> Self {
+        let x = self.x + t * (other.x - self.x);
+        let y = self.y + t * (other.y - self.y);
+        Self { x, y }
+    }
+
+    pub fn dot(&self, other: Self) -> Float {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn magnitude(self) -> Float {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn magnitude_squared(self) -> Float {
+        self.dot(self)
+    }
+
+    pub fn distance(self, other: Self) -> Float {
+        (self - other).magnitude()
+    }
+
+    pub fn unit(self) -> Self {
+        let mag = self.magnitude();
+        if mag > 0.0 { self / mag } else { Self::zero() }
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl Mul<Float> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, scalar: Float) -> Self {
+        Self {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+impl Mul<Vec2> for Float {
+    type Output = Vec2;
+
+    fn mul(self, v: Vec2) -> Vec2 {
+        v * self
+    }
+}
+
+impl MulAssign<Float> for Vec2 {
+    fn mul_assign(&mut self, scalar: Float) {
+        self.x *= scalar;
+        self.y *= scalar;
+    }
+}
+
+impl Div<Float> for Vec2 {
+    type Output = Self;
+
+    fn div(self, scalar: Float) -> Self {
+        Self {
+            x: self.x / scalar,
+            y: self.y / scalar,
+        }
+    }
+}
+
+impl DivAssign<Float> for Vec2 {
+    fn div_assign(&mut self, scalar: Float) {
+        self.x /= scalar;
+        self.y /= scalar;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl From<[Float; 2]> for Vec2 {
+    fn from([x, y]: [Float; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Vec2> for [Float; 2] {
+    fn from(v: Vec2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+/// Drops the z component.
+impl From<Vec3> for Vec2 {
+    fn from(v: Vec3) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+/// Adds a zero z component; see `Vec2::extend` for any other z.
+impl From<Vec2> for Vec3 {
+    fn from(v: Vec2) -> Self {
+        v.extend(0.0)
+    }
+}
+
+impl fmt::Debug for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "({:.*}, {:.*})", p, self.x, p, self.y),
+            None => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_operators() {
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(3.0, -4.0);
+        assert_eq!(a + b, Vec2::new(4.0, -2.0));
+        assert_eq!(a - b, Vec2::new(-2.0, 6.0));
+        assert_eq!(a * 2.0, Vec2::new(2.0, 4.0));
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!(b / 2.0, Vec2::new(1.5, -2.0));
+        assert_eq!(-a, Vec2::new(-1.0, -2.0));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 2.0;
+        c /= 4.0;
+        assert_eq!(c, b / 2.0);
+    }
+
+    #[test]
+    fn dot_magnitude_and_lerp() {
+        let v = Vec2::new(3.0, 4.0);
+        assert_eq!(v.dot(Vec2::new(2.0, -1.0)), 2.0);
+        assert_eq!(v.magnitude(), 5.0);
+        assert_eq!(v.magnitude_squared(), 25.0);
+        assert_eq!(v.distance(Vec2::zero()), 5.0);
+        assert_eq!(v.unit(), Vec2::new(0.6, 0.8));
+        assert_eq!(Vec2::zero().unit(), Vec2::zero());
+        assert_eq!(Vec2::zero().lerp(v, 0.5), Vec2::new(1.5, 2.0));
+    }
+
+    #[test]
+    fn converts_to_and_from_vec3() {
+        let v = Vec2::new(1.0, 2.0);
+        assert_eq!(Vec3::from(v), Vec3::new(1.0, 2.0, 0.0));
+        assert_eq!(v.extend(3.0), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vec2::from(Vec3::new(1.0, 2.0, 3.0)), v);
+        let array: [Float; 2] = v.into();
+        assert_eq!(Vec2::from(array), v);
+    }
+}
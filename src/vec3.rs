@@ -1,9 +1,15 @@
 use core::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::iter::Sum;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
-use crate::Float;
+use crate::{Float, consts::TAU};
 
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 pub struct Vec3 {
     pub x: Float,
     pub y: Float,
@@ -11,11 +17,11 @@ pub struct Vec3 {
 }
 
 impl Vec3 {
-    pub fn new(x: Float, y: Float, z: Float) -> Self {
+    pub const fn new(x: Float, y: Float, z: Float) -> Self {
         Self { x, y, z }
     }
 
-    pub fn zero() -> Self {
+    pub const fn zero() -> Self {
         Self {
             x: 0.0,
             y: 0.0,
@@ -23,7 +29,7 @@ impl Vec3 {
         }
     }
 
-    pub fn up() -> Self {
+    pub const fn up() -> Self {
         Self {
             x: 0.0,
             y: 1.0,
@@ -31,7 +37,7 @@ impl Vec3 {
         }
     }
 
-    pub fn down() -> Self {
+    pub const fn down() -> Self {
         Self {
             x: 0.0,
             y: -1.0,
@@ -39,7 +45,7 @@ impl Vec3 {
         }
     }
 
-    pub fn left() -> Self {
+    pub const fn left() -> Self {
         Self {
             x: -1.0,
             y: 0.0,
@@ -47,7 +53,7 @@ impl Vec3 {
         }
     }
 
-    pub fn right() -> Self {
+    pub const fn right() -> Self {
         Self {
             x: 1.0,
             y: 0.0,
@@ -55,7 +61,7 @@ impl Vec3 {
         }
     }
 
-    pub fn forward() -> Self {
+    pub const fn forward() -> Self {
         Self {
             x: 0.0,
             y: 0.0,
@@ -63,7 +69,7 @@ impl Vec3 {
         }
     }
 
-    pub fn backward() -> Self {
+    pub const fn backward() -> Self {
         Self {
             x: 0.0,
             y: 0.0,
@@ -71,6 +77,20 @@ impl Vec3 {
         }
     }
 
+    pub fn with_x(self, x: Float) -> Self {
+        Self { x, ..self }
+    }
+
+    pub fn with_y(self, y: Float) -> Self {
+        Self { y, ..self }
+    }
+
+    pub fn with_z(self, z: Float) -> Self {
+        Self { z, ..self }
+    }
+
+    /// Linear interpolation. `t` is not clamped, so values outside `[0, 1]`
+    /// extrapolate past the endpoints; see `lerp_clamped`.
     pub fn lerp(self, other: Self, t: Float) -> Self {
         let x = self.x + t * (other.x - self.x);
         let y = self.y + t * (other.y - self.y);
@@ -78,6 +98,46 @@ impl Vec3 {
         Self { x, y, z }
     }
 
+    /// Moves toward `target` by at most `max_distance`, landing exactly on it
+    /// when it's within range. A negative `max_distance` moves away from it,
+    /// except when already on it.
+    pub fn move_toward(self, target: Self, max_distance: Float) -> Self {
+        let offset = target - self;
+        let distance = offset.magnitude();
+        if distance <= max_distance.max(0.0) {
+            return target;
+        }
+        self + offset * (max_distance / distance)
+    }
+
+    /// Like `lerp`, but `t` is clamped to `[0, 1]` so the result always lies
+    /// on the segment between the two vectors.
+    pub fn lerp_clamped(self, other: Self, t: Float) -> Self {
+        self.lerp(other, t.clamp(0.0, 1.0))
+    }
+
+    /// Rotates the vector by `theta` radians about `axis` using Rodrigues'
+    /// formula. The axis is normalized internally.
+    pub fn rotate_around_axis(self, axis: Self, theta: Float) -> Self {
+        let n = axis.unit();
+        let cos = theta.cos();
+        self * cos + n * self.dot(n) * (1.0 - cos) + n.cross(self) * theta.sin()
+    }
+
+    /// Spherical interpolation along the great circle between two unit
+    /// vectors. Both inputs are assumed to be unit length; nearly parallel
+    /// inputs fall back to `lerp`. Opposite vectors have no unique great
+    /// circle, so the result for them is unspecified.
+    pub fn slerp(self, other: Self, t: Float) -> Self {
+        let cos = self.dot(other).clamp(-1.0, 1.0);
+        if cos > 0.9995 {
+            return self.lerp(other, t);
+        }
+        let theta = cos.acos();
+        let sin = theta.sin();
+        self * (((1.0 - t) * theta).sin() / sin) + other * ((t * theta).sin() / sin)
+    }
+
     pub fn dot(&self, other: Self) -> Float {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
@@ -89,8 +149,52 @@ impl Vec3 {
         Self { x, y, z }
     }
 
+    /// The normalized vector plus two unit vectors perpendicular to it,
+    /// returned as a right-handed basis `(n, t, b)` with `n.cross(t) == b`.
+    /// The tangent is built from the world axis along the smallest component
+    /// of `n`, so it never comes from a near-parallel cross product. The
+    /// input must be nonzero.
+    pub fn orthonormal_basis(self) -> (Self, Self, Self) {
+        let n = self.unit();
+        let a = n.abs();
+        let axis = if a.x <= a.y && a.x <= a.z {
+            Self::right()
+        } else if a.y <= a.z {
+            Self::up()
+        } else {
+            Self::backward()
+        };
+        let t = n.cross(axis).unit();
+        let b = n.cross(t);
+        (n, t, b)
+    }
+
     pub fn magnitude(self) -> Float {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn magnitude_squared(self) -> Float {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn distance(self, other: Self) -> Float {
+        (other - self).magnitude()
+    }
+
+    /// True if every component is within `epsilon` of `other`'s.
+    pub fn approx_eq(&self, other: Self, epsilon: Float) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+    }
+
+    /// The largest absolute component, i.e. the infinity norm.
+    pub fn abs_max(&self) -> Float {
+        self.abs().max_element()
+    }
+
+    pub fn abs_min(&self) -> Float {
+        self.abs().min_element()
     }
 
     pub fn unit(self) -> Self {
@@ -106,6 +210,243 @@ impl Vec3 {
             Self::zero()
         }
     }
+
+    /// Normalizes the vector, or returns `None` if its magnitude is within
+    /// `Float::EPSILON` of zero (where `unit()` would return zero).
+    pub fn try_normalize(self) -> Option<Self> {
+        let mag = self.magnitude();
+        if mag <= Float::EPSILON {
+            None
+        } else {
+            Some(self / mag)
+        }
+    }
+
+    /// Like `try_normalize`, but returns `fallback` for a near-zero vector.
+    pub fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    pub fn map<F: Fn(Float) -> Float>(self, f: F) -> Self {
+        Self {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+
+    pub fn abs(self) -> Self {
+        self.map(Float::abs)
+    }
+
+    pub fn floor(self) -> Self {
+        self.map(Float::floor)
+    }
+
+    pub fn ceil(self) -> Self {
+        self.map(Float::ceil)
+    }
+
+    pub fn round(self) -> Self {
+        self.map(Float::round)
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Clamps each component to the matching components of `min` and `max`.
+    /// Unlike `Float::clamp` this never panics: where `min > max`, that
+    /// component is pinned to `min`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.min(max).max(min)
+    }
+
+    pub fn min_element(self) -> Float {
+        self.x.min(self.y).min(self.z)
+    }
+
+    pub fn max_element(self) -> Float {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// The raw bit patterns of the components, for lossless binary storage.
+    /// These are `u64`s, or `u32`s under the `f32` feature.
+    #[cfg(not(feature = "f32"))]
+    pub fn to_bits(self) -> [u64; 3] {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()]
+    }
+
+    /// The raw bit patterns of the components, for lossless binary storage.
+    /// These are `u64`s, or `u32`s under the `f32` feature.
+    #[cfg(feature = "f32")]
+    pub fn to_bits(self) -> [u32; 3] {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()]
+    }
+
+    /// The inverse of `to_bits`; round-trips every value exactly, including
+    /// `-0.0`, subnormals, and NaN payloads.
+    #[cfg(not(feature = "f32"))]
+    pub fn from_bits([x, y, z]: [u64; 3]) -> Self {
+        Self::new(
+            Float::from_bits(x),
+            Float::from_bits(y),
+            Float::from_bits(z),
+        )
+    }
+
+    /// The inverse of `to_bits`; round-trips every value exactly, including
+    /// `-0.0`, subnormals, and NaN payloads.
+    #[cfg(feature = "f32")]
+    pub fn from_bits([x, y, z]: [u32; 3]) -> Self {
+        Self::new(
+            Float::from_bits(x),
+            Float::from_bits(y),
+            Float::from_bits(z),
+        )
+    }
+
+    /// Whether every component is exactly zero (either sign).
+    pub fn is_zero(self) -> bool {
+        self.x == 0.0 && self.y == 0.0 && self.z == 0.0
+    }
+
+    /// Whether the magnitude is below `epsilon`.
+    pub fn approx_zero(self, epsilon: Float) -> bool {
+        self.magnitude() < epsilon
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    pub fn is_nan(self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Angle in radians between the two vectors, in `[0, π]`. Returns 0.0 if
+    /// either vector is zero.
+    pub fn angle_between(self, other: Self) -> Float {
+        let mags = self.magnitude() * other.magnitude();
+        if mags == 0.0 {
+            return 0.0;
+        }
+        (self.dot(other) / mags).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Reflects the vector off a surface with the given normal, which must be
+    /// unit length.
+    pub fn reflect(self, normal: Self) -> Self {
+        self - normal * (2.0 * self.dot(normal))
+    }
+
+    /// The component of the vector parallel to `onto`, or zero if `onto` is
+    /// zero.
+    pub fn project_onto(self, onto: Self) -> Self {
+        let len_sq = onto.magnitude_squared();
+        if len_sq == 0.0 {
+            return Self::zero();
+        }
+        onto * (self.dot(onto) / len_sq)
+    }
+
+    /// The component of the vector perpendicular to `from`.
+    pub fn reject_from(self, from: Self) -> Self {
+        self - self.project_onto(from)
+    }
+
+    /// Scales the vector down to `max` length if it's longer.
+    pub fn clamp_magnitude(self, max: Float) -> Self {
+        let mag = self.magnitude();
+        if mag > max && mag > 0.0 {
+            self * (max / mag)
+        } else {
+            self
+        }
+    }
+
+    /// Scales the vector to `min` length if shorter and to `max` length if
+    /// longer. A zero vector has no direction to scale along, so it is
+    /// returned unchanged as zero even when `min` is positive.
+    pub fn clamp_length(self, min: Float, max: Float) -> Self {
+        let mag = self.magnitude();
+        if mag == 0.0 {
+            Self::zero()
+        } else if mag < min {
+            self * (min / mag)
+        } else if mag > max {
+            self * (max / mag)
+        } else {
+            self
+        }
+    }
+
+    /// True if the three points lie on one line. `epsilon` bounds the area of
+    /// the parallelogram spanned by `b - a` and `c - a` (twice the triangle's
+    /// area), so it scales with the square of the points' spread.
+    pub fn are_collinear(a: Self, b: Self, c: Self, epsilon: Float) -> bool {
+        (b - a).cross(c - a).magnitude() <= epsilon
+    }
+
+    /// True if the four points lie in one plane. `epsilon` bounds the volume
+    /// of the parallelepiped spanned by the edges from `a` (six times the
+    /// tetrahedron's volume), so it scales with the cube of the points' spread.
+    pub fn are_coplanar(a: Self, b: Self, c: Self, d: Self, epsilon: Float) -> bool {
+        (b - a).dot((c - a).cross(d - a)).abs() <= epsilon
+    }
+
+    /// Clockwise angle from `self` to `other` in `[0, 2π)`, measured in the
+    /// plane with normal `plane_normal`. Clockwise is as seen looking down
+    /// `plane_normal` from its tip, so with `Vec3::up()` as the normal the
+    /// bearing from `Vec3::forward()` to `Vec3::right()` is `π/2`. Returns 0.0
+    /// if either vector has no component in the plane.
+    pub fn bearing_to(self, other: Self, plane_normal: Self) -> Float {
+        let n = plane_normal.unit();
+        let a = self - n * self.dot(n);
+        let b = other - n * other.dot(n);
+        if a.dot(a) <= Float::EPSILON * self.dot(self)
+            || b.dot(b) <= Float::EPSILON * other.dot(other)
+        {
+            return 0.0;
+        }
+        let angle = -n.dot(a.cross(b)).atan2(a.dot(b));
+        if angle > 0.0 {
+            angle
+        } else {
+            let wrapped = angle + TAU;
+            if wrapped < TAU { wrapped } else { 0.0 }
+        }
+    }
+}
+
+/// Writes `a[i].dot(b[i])` into `out[i]`. Panics if the lengths differ.
+pub fn batch_dot(a: &[Vec3], b: &[Vec3], out: &mut [Float]) {
+    assert_eq!(a.len(), b.len(), "a and b must have the same length");
+    assert_eq!(a.len(), out.len(), "out must match the input length");
+    for ((a, b), out) in a.iter().zip(b).zip(out) {
+        *out = a.dot(*b);
+    }
+}
+
+/// Writes `a[i].cross(b[i])` into `out[i]`. Panics if the lengths differ.
+pub fn batch_cross(a: &[Vec3], b: &[Vec3], out: &mut [Vec3]) {
+    assert_eq!(a.len(), b.len(), "a and b must have the same length");
+    assert_eq!(a.len(), out.len(), "out must match the input length");
+    for ((a, b), out) in a.iter().zip(b).zip(out) {
+        *out = a.cross(*b);
+    }
 }
 
 impl Add for Vec3 {
@@ -172,6 +513,14 @@ impl Mul<Vec3> for Vec3 {
     }
 }
 
+impl Mul<Vec3> for Float {
+    type Output = Vec3;
+
+    fn mul(self, v: Vec3) -> Vec3 {
+        v * self
+    }
+}
+
 impl MulAssign<Float> for Vec3 {
     fn mul_assign(&mut self, scalar: Float) {
         self.x *= scalar;
@@ -200,6 +549,20 @@ impl Div<Float> for Vec3 {
     }
 }
 
+/// Component-wise division. Dividing by a zero component follows float
+/// rules (infinity or NaN) rather than panicking.
+impl Div<Vec3> for Vec3 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+        }
+    }
+}
+
 impl DivAssign<Float> for Vec3 {
     fn div_assign(&mut self, scalar: Float) {
         self.x /= scalar;
@@ -208,6 +571,14 @@ impl DivAssign<Float> for Vec3 {
     }
 }
 
+impl DivAssign<Vec3> for Vec3 {
+    fn div_assign(&mut self, other: Self) {
+        self.x /= other.x;
+        self.y /= other.y;
+        self.z /= other.z;
+    }
+}
+
 impl Neg for Vec3 {
     type Output = Self;
 
@@ -220,8 +591,545 @@ impl Neg for Vec3 {
     }
 }
 
+impl Sum for Vec3 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, v| acc + v)
+    }
+}
+
+impl<'a> Sum<&'a Vec3> for Vec3 {
+    fn sum<I: Iterator<Item = &'a Vec3>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, v| acc + *v)
+    }
+}
+
+impl Index<usize> for Vec3 {
+    type Output = Float;
+
+    fn index(&self, index: usize) -> &Float {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vec3 index out of range: {index} (expected 0, 1, or 2)"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec3 {
+    fn index_mut(&mut self, index: usize) -> &mut Float {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Vec3 index out of range: {index} (expected 0, 1, or 2)"),
+        }
+    }
+}
+
+impl From<[Float; 3]> for Vec3 {
+    fn from([x, y, z]: [Float; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<Vec3> for [Float; 3] {
+    fn from(v: Vec3) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl From<(Float, Float, Float)> for Vec3 {
+    fn from((x, y, z): (Float, Float, Float)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<Vec3> for (Float, Float, Float) {
+    fn from(v: Vec3) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
 impl fmt::Debug for Vec3 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {}, {})", self.x, self.y, self.z)
     }
 }
+
+/// Prints `(x, y, z)`. A precision such as `{:.2}` is applied to each
+/// component, rounding the same way `{:.2}` does for a float (to nearest,
+/// with exact ties going to even, so `1.25` prints as `1.2`).
+impl fmt::Display for Vec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "({:.*}, {:.*}, {:.*})", p, self.x, p, self.y, p, self.z),
+            None => write!(f, "({}, {}, {})", self.x, self.y, self.z),
+        }
+    }
+}
+
+/// A `Vec3` compared and hashed by the bit patterns of its components, so it
+/// can key a `HashMap` or `HashSet`, e.g. for welding exact duplicate
+/// vertices. Equality is bitwise: `0.0` and `-0.0` are different keys, and a
+/// NaN equals another NaN only if their bits match.
+#[cfg(feature = "hash")]
+#[derive(Clone, Copy, Debug)]
+pub struct Vec3Bits(pub Vec3);
+
+#[cfg(feature = "hash")]
+impl PartialEq for Vec3Bits {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+#[cfg(feature = "hash")]
+impl Eq for Vec3Bits {}
+
+#[cfg(feature = "hash")]
+impl std::hash::Hash for Vec3Bits {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+#[cfg(feature = "hash")]
+impl From<Vec3> for Vec3Bits {
+    fn from(v: Vec3) -> Self {
+        Self(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TEST_EPSILON, consts};
+
+    #[test]
+    fn bearing_to_covers_all_four_quadrants() {
+        let cases = [
+            (Vec3::new(1.0, 0.0, -1.0), consts::FRAC_PI_4),
+            (Vec3::new(1.0, 0.0, 1.0), 3.0 * consts::FRAC_PI_4),
+            (Vec3::new(-1.0, 0.0, 1.0), 5.0 * consts::FRAC_PI_4),
+            (Vec3::new(-1.0, 0.0, -1.0), 7.0 * consts::FRAC_PI_4),
+        ];
+        for (target, expected) in cases {
+            let bearing = Vec3::forward().bearing_to(target, Vec3::up());
+            assert!(
+                (bearing - expected).abs() < TEST_EPSILON,
+                "{bearing} != {expected}"
+            );
+        }
+        assert_eq!(Vec3::forward().bearing_to(Vec3::forward(), Vec3::up()), 0.0);
+
+        // Parallel to the normal, the projection leaves only rounding noise.
+        let n = Vec3::new(1.0, 1.0, 1.0);
+        assert_eq!(n.bearing_to(Vec3::forward(), n), 0.0);
+        assert_eq!(Vec3::forward().bearing_to(n * 2.0, n), 0.0);
+    }
+
+    #[test]
+    fn clamp_length_below_in_and_above_range() {
+        assert_eq!(
+            Vec3::new(1.0, 0.0, 0.0).clamp_length(2.0, 5.0),
+            Vec3::new(2.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            Vec3::new(0.0, 3.0, 0.0).clamp_length(2.0, 5.0),
+            Vec3::new(0.0, 3.0, 0.0)
+        );
+        assert_eq!(
+            Vec3::new(0.0, 0.0, 10.0).clamp_length(2.0, 5.0),
+            Vec3::new(0.0, 0.0, 5.0)
+        );
+        let diagonal = Vec3::new(1.0, 1.0, 1.0).clamp_length(4.0, 5.0);
+        assert!((diagonal.magnitude() - 4.0).abs() < TEST_EPSILON);
+    }
+
+    #[test]
+    fn clamp_length_keeps_zero_input_zero() {
+        assert_eq!(Vec3::zero().clamp_length(2.0, 5.0), Vec3::zero());
+    }
+
+    #[test]
+    fn abs_max_and_abs_min_ignore_sign() {
+        let v = Vec3::new(-3.0, 2.0, -0.5);
+        assert_eq!(v.abs_max(), 3.0);
+        assert_eq!(v.abs_min(), 0.5);
+    }
+
+    #[test]
+    fn batch_dot_and_cross_match_per_element() {
+        let a = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.0, 0.5, 2.0),
+            Vec3::up(),
+        ];
+        let b = [
+            Vec3::new(0.5, -1.0, 2.0),
+            Vec3::new(3.0, 3.0, -1.0),
+            Vec3::right(),
+        ];
+        let mut dots = [0.0; 3];
+        let mut crosses = [Vec3::zero(); 3];
+        batch_dot(&a, &b, &mut dots);
+        batch_cross(&a, &b, &mut crosses);
+        for i in 0..3 {
+            assert_eq!(dots[i], a[i].dot(b[i]));
+            assert_eq!(crosses[i], a[i].cross(b[i]));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "a and b must have the same length")]
+    fn batch_dot_rejects_mismatched_lengths() {
+        batch_dot(&[Vec3::up()], &[], &mut [0.0]);
+    }
+
+    #[test]
+    fn are_collinear_detects_lines() {
+        let a = Vec3::new(1.0, 1.0, 1.0);
+        let b = Vec3::new(2.0, 3.0, 4.0);
+        assert!(Vec3::are_collinear(
+            a,
+            b,
+            Vec3::new(4.0, 7.0, 10.0),
+            TEST_EPSILON
+        ));
+        assert!(!Vec3::are_collinear(
+            a,
+            b,
+            Vec3::new(4.0, 7.0, 11.0),
+            TEST_EPSILON
+        ));
+    }
+
+    #[test]
+    fn are_coplanar_detects_planes() {
+        let a = Vec3::zero();
+        let b = Vec3::new(1.0, 0.0, 1.0);
+        let c = Vec3::new(0.0, 2.0, 0.0);
+        assert!(Vec3::are_coplanar(
+            a,
+            b,
+            c,
+            Vec3::new(3.0, 5.0, 3.0),
+            TEST_EPSILON
+        ));
+        assert!(!Vec3::are_coplanar(
+            a,
+            b,
+            c,
+            Vec3::new(3.0, 5.0, 2.0),
+            TEST_EPSILON
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trips_as_xyz() {
+        let v = Vec3::new(1.5, -2.0, 0.25);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"x":1.5,"y":-2.0,"z":0.25}"#);
+        assert_eq!(serde_json::from_str::<Vec3>(&json).unwrap(), v);
+    }
+
+    #[test]
+    fn approx_eq_tolerates_what_eq_does_not() {
+        let a = Vec3::zero();
+        let b = Vec3::new(0.0, 1e-12, 0.0);
+        assert_ne!(a, b);
+        assert!(a.approx_eq(b, 1e-9));
+        assert!(!a.approx_eq(Vec3::new(0.0, 1e-6, 0.0), 1e-9));
+    }
+
+    #[test]
+    fn magnitude_squared_skips_the_sqrt() {
+        let v = Vec3::new(1.0, 2.0, 2.0);
+        assert_eq!(v.magnitude_squared(), 9.0);
+        assert!((v.magnitude_squared() - v.magnitude().powi(2)).abs() < TEST_EPSILON);
+    }
+
+    #[test]
+    fn angle_between_perpendicular_identical_and_near_parallel() {
+        let angle = Vec3::right().angle_between(Vec3::up());
+        assert!((angle - consts::FRAC_PI_2).abs() < TEST_EPSILON);
+        let v = Vec3::new(0.3, -0.4, 0.5);
+        assert!(v.angle_between(v * 3.0) < TEST_EPSILON * 1e3);
+        assert_eq!(Vec3::zero().angle_between(v), 0.0);
+
+        // For this parallel pair the cosine rounds past 1.0 in both f32 and
+        // f64; it must be clamped rather than turned into NaN by acos.
+        let a = Vec3::new(1.0, 1.0, 4.0);
+        let b = Vec3::new(7.0, 7.0, 28.0);
+        let ratio = a.dot(b) / (a.magnitude() * b.magnitude());
+        assert!(ratio > 1.0, "{ratio}");
+        let angle = a.angle_between(b);
+        assert!(angle.is_finite() && angle.abs() < TEST_EPSILON, "{angle}");
+    }
+
+    #[test]
+    fn reflect_bounces_off_a_floor() {
+        assert_eq!(
+            Vec3::new(1.0, -1.0, 0.0).reflect(Vec3::up()),
+            Vec3::new(1.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn project_onto_and_reject_from_split_a_vector() {
+        let v = Vec3::new(2.0, 3.0, 0.0);
+        assert_eq!(v.project_onto(Vec3::right()), Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(v.reject_from(Vec3::right()), Vec3::new(0.0, 3.0, 0.0));
+        assert_eq!(v.project_onto(Vec3::zero()), Vec3::zero());
+    }
+
+    #[test]
+    fn clamp_magnitude_caps_the_length() {
+        let v = Vec3::new(3.0, 4.0, 0.0);
+        let clamped = v.clamp_magnitude(2.5);
+        assert!((clamped.magnitude() - 2.5).abs() < TEST_EPSILON);
+        assert!(clamped.unit().approx_eq(v.unit(), TEST_EPSILON));
+        assert_eq!(v.clamp_magnitude(10.0), v);
+        assert_eq!(Vec3::zero().clamp_magnitude(2.5), Vec3::zero());
+    }
+
+    #[test]
+    fn array_conversions_round_trip() {
+        let v = Vec3::from([1.0, 2.0, 3.0]);
+        assert_eq!(v, Vec3::new(1.0, 2.0, 3.0));
+        let array: [Float; 3] = v.into();
+        assert_eq!(array, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn tuple_conversions_round_trip() {
+        let v = Vec3::from((1.0, -2.0, 3.5));
+        assert_eq!(v, Vec3::new(1.0, -2.0, 3.5));
+        let (x, y, z): (Float, Float, Float) = v.into();
+        assert_eq!((x, y, z), (1.0, -2.0, 3.5));
+    }
+
+    #[test]
+    fn index_maps_to_components() {
+        let mut v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v[0], v.x);
+        assert_eq!(v[1], v.y);
+        assert_eq!(v[2], v.z);
+        v[1] = 5.0;
+        assert_eq!(v, Vec3::new(1.0, 5.0, 3.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec3 index out of range")]
+    fn index_past_z_panics() {
+        let _ = Vec3::new(1.0, 2.0, 3.0)[3];
+    }
+
+    #[test]
+    fn display_applies_precision_to_each_component() {
+        let v = Vec3::new(1.25, 2.0, 3.0);
+        assert_eq!(format!("{v:.1}"), "(1.2, 2.0, 3.0)");
+        assert_eq!(format!("{v}"), "(1.25, 2, 3)");
+    }
+
+    #[test]
+    fn rounding_helpers_handle_negative_components() {
+        let v = Vec3::new(-1.5, 2.5, -0.5);
+        assert_eq!(v.round(), Vec3::new(-2.0, 3.0, -1.0));
+        assert_eq!(v.floor(), Vec3::new(-2.0, 2.0, -1.0));
+        assert_eq!(v.ceil(), Vec3::new(-1.0, 3.0, 0.0));
+        assert_eq!(v.abs(), Vec3::new(1.5, 2.5, 0.5));
+    }
+
+    #[test]
+    fn min_and_max_work_per_component() {
+        let a = Vec3::new(-1.0, 4.0, 0.5);
+        let b = Vec3::new(2.0, -3.0, 0.5);
+        assert_eq!(a.min(b), Vec3::new(-1.0, -3.0, 0.5));
+        assert_eq!(a.max(b), Vec3::new(2.0, 4.0, 0.5));
+        assert_eq!(a.min_element(), -1.0);
+        assert_eq!(a.max_element(), 4.0);
+    }
+
+    #[test]
+    fn is_finite_and_is_nan_flag_bad_components() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert!(v.is_finite() && !v.is_nan());
+        let inf = Vec3::new(1.0, Float::INFINITY, 3.0);
+        assert!(!inf.is_finite() && !inf.is_nan());
+        let nan = Vec3::new(1.0, 2.0, Float::NAN);
+        assert!(!nan.is_finite() && nan.is_nan());
+    }
+
+    #[test]
+    fn sum_adds_owned_and_borrowed_vectors() {
+        let vectors = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.0, 0.5, 1.0),
+            Vec3::new(0.0, 1.5, -2.0),
+        ];
+        let total = Vec3::new(-3.0, 4.0, 2.0);
+        assert_eq!(vectors.iter().sum::<Vec3>(), total);
+        assert_eq!(vectors.into_iter().sum::<Vec3>(), total);
+        assert_eq!(std::iter::empty::<Vec3>().sum::<Vec3>(), Vec3::zero());
+    }
+
+    #[test]
+    fn map_applies_to_each_component() {
+        let v = Vec3::new(1.0, -2.0, 3.5);
+        assert_eq!(v.map(|c| c * 2.0), Vec3::new(2.0, -4.0, 7.0));
+    }
+
+    #[test]
+    fn with_methods_change_only_one_component() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.with_x(9.0), Vec3::new(9.0, 2.0, 3.0));
+        assert_eq!(v.with_y(0.0), Vec3::new(1.0, 0.0, 3.0));
+        assert_eq!(v.with_z(-1.0), Vec3::new(1.0, 2.0, -1.0));
+    }
+
+    #[test]
+    fn scalar_multiplication_commutes() {
+        let v = Vec3::new(1.5, -2.0, 0.25);
+        assert_eq!(2.0 * v, v * 2.0);
+    }
+
+    #[test]
+    fn component_wise_division() {
+        let mut v = Vec3::new(6.0, 8.0, 10.0);
+        let divisor = Vec3::new(2.0, 4.0, 5.0);
+        assert_eq!(v / divisor, Vec3::new(3.0, 2.0, 2.0));
+        v /= divisor;
+        assert_eq!(v, Vec3::new(3.0, 2.0, 2.0));
+        assert!((v / Vec3::zero()).x.is_infinite());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn slices_cast_to_bytes_and_back() {
+        let points = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(-4.0, 5.5, 0.25)];
+        let bytes: &[u8] = bytemuck::cast_slice(&points);
+        let width = std::mem::size_of::<Float>();
+        assert_eq!(bytes.len(), 6 * width);
+        assert_eq!(bytes[width..2 * width], Float::to_ne_bytes(2.0));
+        let back: &[Vec3] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, points);
+    }
+
+    #[test]
+    fn lerp_clamped_stays_between_the_endpoints() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(2.0, 4.0, -2.0);
+        assert_eq!(a.lerp_clamped(b, -1.0), a);
+        assert_eq!(a.lerp_clamped(b, 2.0), b);
+        assert_eq!(a.lerp(b, -1.0), Vec3::new(-2.0, -4.0, 2.0));
+        assert_eq!(a.lerp(b, 2.0), Vec3::new(4.0, 8.0, -4.0));
+    }
+
+    #[test]
+    fn slerp_halfway_from_right_to_up() {
+        let half = Vec3::right().slerp(Vec3::up(), 0.5);
+        assert!((half.magnitude() - 1.0).abs() < TEST_EPSILON);
+        assert!((half.angle_between(Vec3::right()) - consts::FRAC_PI_4).abs() < TEST_EPSILON * 1e3);
+        assert!((half.angle_between(Vec3::up()) - consts::FRAC_PI_4).abs() < TEST_EPSILON * 1e3);
+    }
+
+    #[test]
+    fn rotate_around_axis_turns_right_into_forward() {
+        let rotated = Vec3::right().rotate_around_axis(Vec3::up() * 3.0, consts::FRAC_PI_2);
+        assert!(rotated.approx_eq(Vec3::forward(), TEST_EPSILON));
+    }
+
+    #[test]
+    fn orthonormal_basis_is_perpendicular_and_unit() {
+        let inputs = [
+            Vec3::right(),
+            Vec3::up(),
+            Vec3::forward(),
+            Vec3::new(0.0, 0.0, 5.0),
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-0.3, 0.01, 0.9),
+        ];
+        for v in inputs {
+            let (n, t, b) = v.orthonormal_basis();
+            assert!(n.approx_eq(v.unit(), TEST_EPSILON));
+            for axis in [n, t, b] {
+                assert!((axis.magnitude() - 1.0).abs() < TEST_EPSILON, "{v:?}");
+            }
+            assert!(n.dot(t).abs() < TEST_EPSILON, "{v:?}");
+            assert!(t.dot(b).abs() < TEST_EPSILON, "{v:?}");
+            assert!(b.dot(n).abs() < TEST_EPSILON, "{v:?}");
+        }
+    }
+
+    #[test]
+    fn move_toward_snaps_to_the_target() {
+        let start = Vec3::new(1.0, 1.0, 1.0);
+        let target = Vec3::new(1.0, 4.0, 5.0);
+        assert_eq!(start.move_toward(target, 10.0), target);
+        assert!(
+            start
+                .move_toward(target, 2.5)
+                .approx_eq(Vec3::new(1.0, 2.5, 3.0), TEST_EPSILON)
+        );
+        assert_eq!(target.move_toward(target, 1.0), target);
+        assert_eq!(target.move_toward(target, -1.0), target);
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn vec3_bits_dedups_identical_vectors() {
+        use std::collections::HashSet;
+
+        let vertices = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(-0.0, 0.0, 0.0),
+        ];
+        let unique: HashSet<Vec3Bits> = vertices.into_iter().map(Vec3Bits::from).collect();
+        // 0.0 and -0.0 have different bits, so they stay distinct.
+        assert_eq!(unique.len(), 3);
+        assert!(unique.contains(&Vec3Bits(Vec3::new(1.0, 2.0, 3.0))));
+        assert!(unique.contains(&Vec3Bits(Vec3::new(-0.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn clamp_pins_each_component_into_the_box() {
+        let v = Vec3::new(5.0, -5.0, 0.5);
+        let clamped = v.clamp(Vec3::zero(), Vec3::new(1.0, 1.0, 1.0));
+        assert_eq!(clamped, Vec3::new(1.0, 0.0, 0.5));
+    }
+
+    #[test]
+    fn normalizing_zero_uses_the_fallback() {
+        assert_eq!(Vec3::zero().try_normalize(), None);
+        assert_eq!(Vec3::zero().normalize_or(Vec3::up()), Vec3::up());
+
+        let v = Vec3::new(0.0, 3.0, 4.0);
+        assert_eq!(v.try_normalize(), Some(Vec3::new(0.0, 0.6, 0.8)));
+        assert_eq!(v.normalize_or(Vec3::up()), Vec3::new(0.0, 0.6, 0.8));
+    }
+
+    #[test]
+    fn is_zero_and_approx_zero() {
+        assert!(Vec3::zero().is_zero() && Vec3::zero().approx_zero(1e-6));
+        let tiny = Vec3::new(1e-9, 0.0, -1e-9);
+        assert!(!tiny.is_zero() && tiny.approx_zero(1e-6));
+        let v = Vec3::new(0.0, 0.5, 0.0);
+        assert!(!v.is_zero() && !v.approx_zero(1e-6));
+    }
+
+    #[test]
+    fn bits_round_trip_subnormal_and_negative_zero() {
+        let subnormal = Float::MIN_POSITIVE / 4.0;
+        assert!(subnormal.is_subnormal());
+        let v = Vec3::new(subnormal, -0.0, -1.5);
+        let back = Vec3::from_bits(v.to_bits());
+        assert_eq!(back.to_bits(), v.to_bits());
+        assert!(back.x.is_subnormal());
+        assert!(back.y == 0.0 && back.y.is_sign_negative());
+    }
+}
@@ -0,0 +1,174 @@
+use core::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Float, Vec3};
+
+/// A homogeneous 4D vector, mainly for running points through the 4x4
+/// projection matrices from `CFrame::perspective` and friends.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Vec4 {
+    pub x: Float,
+    pub y: Float,
+    pub z: Float,
+    pub w: Float,
+}
+
+impl Vec4 {
+    pub const fn new(x: Float, y: Float, z: Float, w: Float) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Extends `v` with the given `w`: 1 for a point, 0 for a direction.
+    pub const fn from_vec3(v: Vec3, w: Float) -> Self {
+        Self::new(v.x, v.y, v.z, w)
+    }
+
+    /// Drops `w` without dividing by it.
+    pub const fn truncate(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(&self, other: Self) -> Float {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+}
+
+/// Multiplies a column-major 4x4 matrix, as returned by `CFrame::to_array`
+/// or `CFrame::perspective`, by `v`.
+pub fn mat4_mul_vec4(m: &[Float; 16], v: Vec4) -> Vec4 {
+    Vec4 {
+        x: m[0] * v.x + m[4] * v.y + m[8] * v.z + m[12] * v.w,
+        y: m[1] * v.x + m[5] * v.y + m[9] * v.z + m[13] * v.w,
+        z: m[2] * v.x + m[6] * v.y + m[10] * v.z + m[14] * v.w,
+        w: m[3] * v.x + m[7] * v.y + m[11] * v.z + m[15] * v.w,
+    }
+}
+
+/// Transforms `point` as `(x, y, z, 1)` by a column-major 4x4 matrix and
+/// divides by the resulting w, giving NDC coordinates for a projection
+/// matrix. A point with w = 0 (on the camera plane) can't be divided, so its
+/// undivided xyz is returned instead.
+pub fn project_point(matrix: &[Float; 16], point: Vec3) -> Vec3 {
+    let clip = mat4_mul_vec4(matrix, Vec4::from_vec3(point, 1.0));
+    if clip.w == 0.0 {
+        clip.truncate()
+    } else {
+        clip.truncate() / clip.w
+    }
+}
+
+impl Add for Vec4 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+}
+
+impl Sub for Vec4 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+}
+
+impl Mul<Float> for Vec4 {
+    type Output = Self;
+
+    fn mul(self, scalar: Float) -> Self {
+        Self {
+            x: self.x * scalar,
+            y: self.y * scalar,
+            z: self.z * scalar,
+            w: self.w * scalar,
+        }
+    }
+}
+
+impl Div<Float> for Vec4 {
+    type Output = Self;
+
+    fn div(self, scalar: Float) -> Self {
+        Self {
+            x: self.x / scalar,
+            y: self.y / scalar,
+            z: self.z / scalar,
+            w: self.w / scalar,
+        }
+    }
+}
+
+impl Neg for Vec4 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+impl From<[Float; 4]> for Vec4 {
+    fn from([x, y, z, w]: [Float; 4]) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+impl From<Vec4> for [Float; 4] {
+    fn from(v: Vec4) -> Self {
+        [v.x, v.y, v.z, v.w]
+    }
+}
+
+impl fmt::Debug for Vec4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CFrame;
+
+    #[test]
+    fn identity_matrix_leaves_a_vec4_alone() {
+        let v = Vec4::new(1.5, -2.0, 3.0, 1.0);
+        assert_eq!(mat4_mul_vec4(&CFrame::identity().to_array(), v), v);
+        assert_eq!(Vec4::from_vec3(Vec3::new(1.5, -2.0, 3.0), 1.0), v);
+        assert_eq!(v.truncate(), Vec3::new(1.5, -2.0, 3.0));
+    }
+
+    #[test]
+    fn projected_point_in_front_of_the_camera_is_inside_ndc() {
+        let projection = CFrame::perspective(1.2, 16.0 / 9.0, 0.1, 100.0);
+        let ndc = project_point(&projection, Vec3::new(1.0, -0.5, -5.0));
+        for c in [ndc.x, ndc.y, ndc.z] {
+            assert!((-1.0..=1.0).contains(&c), "{ndc:?}");
+        }
+        // A point on the camera plane has w = 0 and comes back undivided.
+        let on_plane = project_point(&projection, Vec3::new(1.0, 1.0, 0.0));
+        assert!(on_plane.is_finite());
+    }
+}